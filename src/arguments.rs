@@ -0,0 +1,15 @@
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::invalid_arguments;
+
+/// Deserializes tool or prompt arguments, treating missing arguments as an empty object.
+pub fn parse_arguments<T: DeserializeOwned>(arguments: Option<Value>) -> Result<T> {
+    let arguments = match arguments {
+        None | Some(Value::Null) => Value::Object(Default::default()),
+        Some(arguments) => arguments,
+    };
+
+    serde_json::from_value(arguments).map_err(|e| invalid_arguments!("Invalid arguments: {}", e))
+}
@@ -0,0 +1,399 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    iter::successors,
+};
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate, Weekday};
+use context_server::{Tool, ToolContent, ToolExecutor};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    error::invalid_arguments,
+    holidays::{find_country, find_subdivision, holidays, Country},
+    settings::settings,
+    timezone::{default_timezone, now, parse_timezone},
+};
+
+const MAX_WORKING_DAYS: i64 = 100_000;
+/// Longest span whose public holidays are computed year by year.
+const MAX_HOLIDAY_YEARS: i32 = 10_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Weekend {
+    #[default]
+    SatSun,
+    FriSat,
+    Sun,
+}
+
+impl Weekend {
+    pub fn contains(self, weekday: Weekday) -> bool {
+        match self {
+            Weekend::SatSun => matches!(weekday, Weekday::Sat | Weekday::Sun),
+            Weekend::FriSat => matches!(weekday, Weekday::Fri | Weekday::Sat),
+            Weekend::Sun => weekday == Weekday::Sun,
+        }
+    }
+}
+
+/// A weekend definition plus individual non-working dates and, optionally, public holidays.
+pub struct WorkingCalendar {
+    weekend: Weekend,
+    non_working: HashSet<NaiveDate>,
+    holidays: Option<(&'static Country, Option<&'static str>)>,
+    holidays_by_year: RefCell<HashMap<i32, HashSet<NaiveDate>>>,
+}
+
+impl WorkingCalendar {
+    pub fn new(weekend: Weekend, non_working: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            weekend,
+            non_working: non_working.into_iter().collect(),
+            holidays: None,
+            holidays_by_year: Default::default(),
+        }
+    }
+
+    /// Also treats the public holidays of `country` (and `subdivision`) as non-working.
+    pub fn with_holidays(
+        mut self,
+        country: &'static Country,
+        subdivision: Option<&'static str>,
+    ) -> Self {
+        self.holidays = Some((country, subdivision));
+        self
+    }
+
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        !self.weekend.contains(date.weekday())
+            && !self.non_working.contains(&date)
+            && !self.is_holiday(date)
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let Some((country, subdivision)) = self.holidays else {
+            return false;
+        };
+
+        // Observed days can cross New Year, so the neighbouring years' calendars count too.
+        let mut holidays_by_year = self.holidays_by_year.borrow_mut();
+        (date.year() - 1..=date.year() + 1).any(|year| {
+            holidays_by_year
+                .entry(year)
+                .or_insert_with(|| {
+                    holidays(country, year, subdivision)
+                        .into_iter()
+                        .flat_map(|holiday| [holiday.date, holiday.observed])
+                        .collect()
+                })
+                .contains(&date)
+        })
+    }
+
+    /// Counts working days in `[start, end)`. Weekdays are counted in whole weeks, so only the
+    /// non-working dates and holidays inside the span are looked at one by one.
+    pub fn count(&self, start: NaiveDate, end: NaiveDate) -> Result<i64> {
+        if end <= start {
+            return Ok(0);
+        }
+        if self.holidays.is_some() && end.year() - start.year() > MAX_HOLIDAY_YEARS {
+            bail!(invalid_arguments!(
+                "Invalid arguments: spans with public holidays must be within {} years",
+                MAX_HOLIDAY_YEARS
+            ));
+        }
+
+        let weekdays = |days: i64| {
+            successors(Some(start.weekday()), |weekday| Some(weekday.succ()))
+                .take(days as usize)
+                .filter(|weekday| !self.weekend.contains(*weekday))
+                .count() as i64
+        };
+        let days = (end - start).num_days();
+        let weekdays = days / 7 * weekdays(7) + weekdays(days % 7);
+
+        let in_span = |date: &NaiveDate| start <= *date && *date < end;
+        let mut days_off = self
+            .non_working
+            .iter()
+            .copied()
+            .filter(in_span)
+            .collect::<HashSet<_>>();
+        if let Some((country, subdivision)) = self.holidays {
+            // Observed days can cross New Year, so the neighbouring years' calendars count too.
+            for year in start.year() - 1..=end.year() + 1 {
+                days_off.extend(
+                    holidays(country, year, subdivision)
+                        .into_iter()
+                        .flat_map(|holiday| [holiday.date, holiday.observed])
+                        .filter(in_span),
+                );
+            }
+        }
+
+        Ok(weekdays
+            - days_off
+                .iter()
+                .filter(|date| !self.weekend.contains(date.weekday()))
+                .count() as i64)
+    }
+
+    /// Moves `days` working days from `start`, not counting `start` itself.
+    pub fn add(&self, start: NaiveDate, days: i64) -> Result<NaiveDate> {
+        if days.abs() > MAX_WORKING_DAYS {
+            bail!(invalid_arguments!(
+                "Invalid arguments: days must be within ±{}",
+                MAX_WORKING_DAYS
+            ));
+        }
+
+        let mut date = start;
+        let mut remaining = days.abs();
+        while remaining > 0 {
+            date = if days > 0 {
+                date.succ_opt()
+            } else {
+                date.pred_opt()
+            }
+            .ok_or_else(|| invalid_arguments!("Resulting date is out of range"))?;
+
+            if self.is_working_day(date) {
+                remaining -= 1;
+            }
+        }
+
+        Ok(date)
+    }
+}
+
+#[derive(Deserialize)]
+struct BusinessDaysArguments {
+    start: Option<String>,
+    end: Option<String>,
+    days: Option<i64>,
+    weekend: Option<Weekend>,
+    #[serde(default)]
+    non_working_dates: Vec<String>,
+    country: Option<String>,
+    subdivision: Option<String>,
+    timezone: Option<String>,
+}
+
+pub struct BusinessDaysTool;
+
+#[async_trait]
+impl ToolExecutor for BusinessDaysTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: BusinessDaysArguments = parse_arguments(arguments)?;
+        let start = match arguments.start.as_deref() {
+            Some(start) => parse_date(start)?,
+            None => {
+                let timezone = match arguments.timezone.as_deref() {
+                    Some(name) => parse_timezone(name)?,
+                    None => default_timezone(),
+                };
+                now(timezone).date_naive()
+            }
+        };
+        let settings = settings();
+        let weekend = arguments
+            .weekend
+            .or_else(|| settings.working_hours.map(|hours| hours.weekend))
+            .unwrap_or_default();
+        let mut non_working = arguments
+            .non_working_dates
+            .iter()
+            .map(|date| parse_date(date))
+            .collect::<Result<Vec<_>>>()?;
+        non_working.extend(settings.holidays.iter().map(|holiday| holiday.date));
+        let mut calendar = WorkingCalendar::new(weekend, non_working);
+        if let Some(country) = arguments.country.as_deref() {
+            let country = find_country(country)?;
+            let subdivision = arguments
+                .subdivision
+                .as_deref()
+                .map(|code| find_subdivision(country, code))
+                .transpose()?;
+            calendar = calendar.with_holidays(country, subdivision);
+        } else if arguments.subdivision.is_some() {
+            bail!(invalid_arguments!(
+                "Invalid arguments: subdivision requires country"
+            ));
+        }
+
+        let text = match (arguments.end.as_deref(), arguments.days) {
+            (Some(end), None) => {
+                let end = parse_date(end)?;
+                let (from, to) = if end < start {
+                    (end, start)
+                } else {
+                    (start, end)
+                };
+                let working = calendar.count(from, to)?;
+                let total = (to - from).num_days();
+
+                format!(
+                    "Working days from {} up to {}: {}\nNon-working days: {}",
+                    from,
+                    to,
+                    working,
+                    total - working
+                )
+            }
+            (None, Some(days)) => {
+                let date = calendar.add(start, days)?;
+
+                format!(
+                    "{} working days from {}: {} ({})",
+                    days,
+                    start,
+                    date,
+                    date.format("%A")
+                )
+            }
+            _ => {
+                bail!(invalid_arguments!(
+                    "Invalid arguments: pass exactly one of end or days"
+                ))
+            }
+        };
+
+        Ok(vec![ToolContent::Text { text }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "business_days".into(),
+            description: Some(
+                "Count working days between two dates, or find the date a number of working days away. With end, counts working days from start up to but excluding end. With days, steps that many working days from start (negative goes backwards), not counting start itself. Weekend days, non_working_dates, holidays from the config file and, when country is given, public holidays from the bundled calendar (both the holiday and its observed substitute day) are skipped.".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "start": {
+                        "type": "string",
+                        "description": "Start date (YYYY-MM-DD). Defaults to today.",
+                    },
+                    "end": {
+                        "type": "string",
+                        "description": "End date (YYYY-MM-DD) to count working days up to.",
+                    },
+                    "days": {
+                        "type": "integer",
+                        "description": "Number of working days to move from start.",
+                    },
+                    "weekend": {
+                        "type": "string",
+                        "enum": ["sat_sun", "fri_sat", "sun"],
+                        "description": "Which days make up the weekend. Defaults to the configured working week, else \"sat_sun\".",
+                    },
+                    "non_working_dates": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Additional non-working dates (YYYY-MM-DD), such as holidays.",
+                    },
+                    "country": {
+                        "type": "string",
+                        "description": "ISO 3166-1 alpha-2 country code whose public holidays are non-working, e.g. \"US\".",
+                    },
+                    "subdivision": {
+                        "type": "string",
+                        "description": "Subdivision code to include regional holidays, e.g. \"BY\" or \"DE-BY\".",
+                    },
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA time zone used to determine today. Defaults to the server's default time zone.",
+                    },
+                },
+            }),
+        }
+    }
+}
+
+fn parse_date(input: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map_err(|_| invalid_arguments!("Invalid date: {} (expected YYYY-MM-DD)", input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(input: &str) -> NaiveDate {
+        input.parse().unwrap()
+    }
+
+    fn count_day_by_day(calendar: &WorkingCalendar, start: NaiveDate, end: NaiveDate) -> i64 {
+        start
+            .iter_days()
+            .take_while(|date| *date < end)
+            .filter(|date| calendar.is_working_day(*date))
+            .count() as i64
+    }
+
+    #[test]
+    fn count_matches_a_day_by_day_walk() {
+        let us = find_country("US").unwrap();
+        let calendars = [
+            WorkingCalendar::new(Weekend::SatSun, []),
+            WorkingCalendar::new(Weekend::FriSat, [date("2026-03-03"), date("2026-03-07")]),
+            WorkingCalendar::new(Weekend::Sun, []).with_holidays(us, None),
+            WorkingCalendar::new(Weekend::SatSun, [date("2026-12-24")])
+                .with_holidays(us, Some("TX")),
+        ];
+        let spans = [
+            ("2026-10-14", "2026-10-14"),
+            ("2026-10-14", "2026-10-19"),
+            ("2026-02-27", "2026-03-10"),
+            ("2025-12-20", "2027-01-10"),
+            ("2021-12-31", "2022-01-01"),
+        ];
+        for calendar in &calendars {
+            for (start, end) in spans {
+                let (start, end) = (date(start), date(end));
+                assert_eq!(
+                    calendar.count(start, end).unwrap(),
+                    count_day_by_day(calendar, start, end)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn count_handles_long_spans() {
+        let calendar = WorkingCalendar::new(Weekend::SatSun, []);
+        let end = NaiveDate::MAX;
+        assert!(calendar.count(NaiveDate::MIN, end).unwrap() > 0);
+        assert_eq!(calendar.count(end, NaiveDate::MIN).unwrap(), 0);
+
+        let calendar = calendar.with_holidays(find_country("US").unwrap(), None);
+        assert!(calendar.count(date("0001-01-01"), end).is_err());
+    }
+
+    #[test]
+    fn add_skips_weekends_and_holidays() {
+        let calendar = WorkingCalendar::new(Weekend::SatSun, [date("2026-12-24")])
+            .with_holidays(find_country("US").unwrap(), None);
+        assert_eq!(
+            calendar.add(date("2026-12-23"), 1).unwrap(),
+            date("2026-12-28")
+        );
+        assert_eq!(
+            calendar.add(date("2026-12-28"), -1).unwrap(),
+            date("2026-12-23")
+        );
+        assert_eq!(
+            calendar.add(date("2026-10-16"), 0).unwrap(),
+            date("2026-10-16")
+        );
+        assert!(calendar
+            .add(date("2026-10-16"), MAX_WORKING_DAYS + 1)
+            .is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use anyhow::{bail, Result};
+use chrono::{Datelike, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::{OffsetName, TZ_VARIANTS};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    error::{invalid_arguments, not_found},
+    format::{parse_locale, PRESETS},
+    gazetteer::{find_cities, find_zone, fold, CITIES},
+    holidays::COUNTRIES,
+    settings::settings,
+    ContextServerState,
+};
+
+/// Most values a `completion/complete` result may carry.
+const MAX_VALUES: usize = 100;
+
+/// Locales offered for completion; any locale `parse_locale` accepts can still be typed in.
+const LOCALES: &[&str] = &[
+    "ar-SA", "cs-CZ", "da-DK", "de-AT", "de-CH", "de-DE", "el-GR", "en-AU", "en-CA", "en-GB",
+    "en-IE", "en-IN", "en-NZ", "en-US", "en-ZA", "es-AR", "es-ES", "es-MX", "fi-FI", "fr-BE",
+    "fr-CA", "fr-CH", "fr-FR", "he-IL", "hi-IN", "hu-HU", "id-ID", "it-IT", "ja-JP", "ko-KR",
+    "nb-NO", "nl-BE", "nl-NL", "pl-PL", "pt-BR", "pt-PT", "ro-RO", "ru-RU", "sv-SE", "tr-TR",
+    "uk-UA", "vi-VN", "zh-CN", "zh-TW",
+];
+
+#[derive(Deserialize)]
+struct CompleteParams {
+    #[serde(rename = "ref")]
+    reference: Reference,
+    argument: Argument,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum Reference {
+    #[serde(rename = "ref/prompt")]
+    Prompt { name: String },
+    #[serde(rename = "ref/resource")]
+    Resource { uri: String },
+}
+
+#[derive(Deserialize)]
+struct Argument {
+    name: String,
+    value: String,
+}
+
+/// A completion value and the normalized keys it is matched on.
+struct Candidate {
+    value: String,
+    names: Vec<String>,
+    abbreviations: Vec<String>,
+}
+
+impl Candidate {
+    fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self {
+            names: vec![normalize(&value)],
+            value,
+            abbreviations: Vec::new(),
+        }
+    }
+
+    /// Lower is better: exact, prefix, word prefix, substring, then in-order characters.
+    /// Abbreviations only match exactly or by prefix.
+    fn rank(&self, query: &str) -> Option<u8> {
+        let names = self.names.iter().filter_map(|name| {
+            if name == query {
+                Some(0)
+            } else if name.starts_with(query) {
+                Some(1)
+            } else if name
+                .match_indices(query)
+                .any(|(index, _)| name[..index].ends_with(['/', ' ']))
+            {
+                Some(2)
+            } else if name.contains(query) {
+                Some(3)
+            } else {
+                is_subsequence(query, name).then_some(4)
+            }
+        });
+        let abbreviations = self.abbreviations.iter().filter_map(|abbreviation| {
+            if abbreviation == query {
+                Some(1)
+            } else {
+                abbreviation.starts_with(query).then_some(2)
+            }
+        });
+
+        names.chain(abbreviations).min()
+    }
+}
+
+/// Answers `completion/complete` for the arguments of prompts and the variables of resource
+/// templates.
+pub fn complete(server: &ContextServerState, params: Option<Value>) -> Result<Value> {
+    let params: CompleteParams = parse_arguments(params)?;
+    let argument = params.argument.name.as_str();
+
+    let candidates = match &params.reference {
+        Reference::Prompt { name } => {
+            let prompt = server
+                .prompt_registry
+                .list_prompts()
+                .into_iter()
+                .find(|prompt| &prompt.name == name)
+                .ok_or_else(|| not_found!("Prompt not found: {}", name))?;
+            if !prompt.arguments.iter().any(|a| a.name == argument) {
+                bail!(invalid_arguments!(
+                    "Prompt {} has no argument {}",
+                    name,
+                    argument
+                ));
+            }
+
+            match argument {
+                "timezone" => zone_candidates(),
+                "locale" => locale_candidates(),
+                "format" => format_candidates(),
+                "verbosity" => ["brief", "standard", "full"].map(Candidate::new).into(),
+                _ => Vec::new(),
+            }
+        }
+        Reference::Resource { uri } => {
+            let template = server
+                .resource_registry
+                .list_templates()
+                .into_iter()
+                .find(|template| &template.uri_template == uri)
+                .ok_or_else(|| not_found!("Resource template not found: {}", uri))?;
+            if !template.uri_template.contains(&format!("{{{}}}", argument)) {
+                bail!(invalid_arguments!(
+                    "Resource template {} has no variable {}",
+                    uri,
+                    argument
+                ));
+            }
+
+            match argument {
+                "tz" => zone_candidates(),
+                "country" => COUNTRIES
+                    .iter()
+                    .map(|country| Candidate {
+                        value: country.code.to_string(),
+                        names: vec![normalize(country.code), normalize(country.name)],
+                        abbreviations: Vec::new(),
+                    })
+                    .collect(),
+                "month" => (1..=12)
+                    .map(|month| Candidate::new(format!("{:02}", month)))
+                    .collect(),
+                _ => Vec::new(),
+            }
+        }
+    };
+
+    let values = rank(candidates, &normalize(&params.argument.value));
+
+    Ok(json!({
+        "completion": {
+            "values": values.iter().take(MAX_VALUES).collect::<Vec<_>>(),
+            "total": values.len(),
+            "hasMore": values.len() > MAX_VALUES,
+        },
+    }))
+}
+
+/// Matching values, best first; ties go to the shorter value, then alphabetically.
+fn rank(candidates: Vec<Candidate>, query: &str) -> Vec<String> {
+    let mut ranked = candidates
+        .into_iter()
+        .filter_map(|candidate| Some((candidate.rank(query)?, candidate.value)))
+        .collect::<Vec<_>>();
+    ranked.sort_by(|(a_rank, a), (b_rank, b)| (a_rank, a.len(), a).cmp(&(b_rank, b.len(), b)));
+    ranked.dedup_by(|(_, a), (_, b)| a == b);
+
+    ranked.into_iter().map(|(_, value)| value).collect()
+}
+
+/// Team zones, gazetteer cities and IANA zones. Zones match on their full name, their city and
+/// the abbreviations they use in January and July of this year.
+fn zone_candidates() -> Vec<Candidate> {
+    let year = Utc::now().year();
+    let instants = [1, 7]
+        .iter()
+        .filter_map(|month| NaiveDate::from_ymd_opt(year, *month, 1))
+        .map(|date| date.and_time(NaiveTime::MIN))
+        .collect::<Vec<_>>();
+
+    let team_zones = settings().team_zones.into_keys().map(Candidate::new);
+    let zones = TZ_VARIANTS.iter().map(|tz| {
+        let name = tz.name();
+        let mut names = vec![normalize(name)];
+        if let Some((_, city)) = name.rsplit_once('/') {
+            names.push(normalize(city));
+        }
+        let mut abbreviations = instants
+            .iter()
+            .filter_map(|utc| {
+                let offset = tz.offset_from_utc_datetime(utc);
+                let abbreviation = offset.abbreviation()?;
+                abbreviation
+                    .chars()
+                    .all(|c| c.is_ascii_alphabetic())
+                    .then(|| abbreviation.to_ascii_lowercase())
+            })
+            .collect::<Vec<_>>();
+        abbreviations.dedup();
+
+        Candidate {
+            value: name.to_string(),
+            names,
+            abbreviations,
+        }
+    });
+
+    // Cities complete to their bare name when that resolves to them, else to their label.
+    let cities = CITIES.iter().map(|city| {
+        let resolves = matches!(find_zone(city.name), Ok(Some(_)))
+            && find_cities(city.name)
+                .first()
+                .is_some_and(|first| std::ptr::eq(*first, city));
+        let value = match resolves {
+            true => city.name.to_string(),
+            false => city.label(),
+        };
+        Candidate {
+            value,
+            names: std::iter::once(city.name)
+                .chain(city.aliases.iter().copied())
+                .map(normalize)
+                .collect(),
+            abbreviations: Vec::new(),
+        }
+    });
+
+    team_zones.chain(cities).chain(zones).collect()
+}
+
+fn locale_candidates() -> Vec<Candidate> {
+    LOCALES
+        .iter()
+        .filter(|locale| parse_locale(locale).is_ok())
+        .map(|locale| Candidate::new(*locale))
+        .collect()
+}
+
+fn format_candidates() -> Vec<Candidate> {
+    settings()
+        .formats
+        .into_keys()
+        .chain(PRESETS.iter().map(|preset| preset.to_string()))
+        .map(Candidate::new)
+        .collect()
+}
+
+/// Folded like the gazetteer, with underscores as spaces, so "new york" finds "America/New_York".
+fn normalize(input: &str) -> String {
+    fold(input).replace('_', " ")
+}
+
+fn is_subsequence(query: &str, name: &str) -> bool {
+    let mut chars = name.chars();
+    query.chars().all(|c| chars.any(|n| n == c))
+}
@@ -0,0 +1,194 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{NaiveDate, NaiveTime};
+use serde::Deserialize;
+
+use crate::{
+    business_days::Weekend,
+    format::{parse_locale, TimeFormat},
+    settings::{CustomHoliday, ResourceUpdates, Selection, Settings, WorkingHours},
+    timezone::parse_timezone_with,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The contents of `config.toml`. Every key is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    timezone: Option<String>,
+    locale: Option<String>,
+    format: Option<String>,
+    #[serde(default)]
+    enable: Vec<String>,
+    #[serde(default)]
+    disable: Vec<String>,
+    #[serde(default)]
+    team_zones: BTreeMap<String, String>,
+    #[serde(default)]
+    formats: BTreeMap<String, String>,
+    working_hours: Option<WorkingHoursConfig>,
+    #[serde(default)]
+    holidays: Vec<HolidayConfig>,
+    /// Seconds a tool call may run.
+    tool_timeout: Option<u64>,
+    /// Seconds a tool call may run, by tool name.
+    #[serde(default)]
+    tool_timeouts: BTreeMap<String, u64>,
+    #[serde(default)]
+    resource_updates: ResourceUpdates,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WorkingHoursConfig {
+    start: String,
+    end: String,
+    #[serde(default)]
+    weekend: Weekend,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HolidayConfig {
+    date: String,
+    name: String,
+}
+
+impl Config {
+    /// Reads the file at `path`; a missing file is an empty configuration.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Invalid config file {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => {
+                Err(e).with_context(|| format!("Failed to read config file {}", path.display()))
+            }
+        }
+    }
+
+    /// Names in `timezone` and `format` refer to this file's `team_zones` and `formats`, never
+    /// to those of the configuration currently in effect.
+    pub fn settings(&self) -> Result<Settings> {
+        let team_zones = self
+            .team_zones
+            .iter()
+            .map(|(name, zone)| Ok((name.clone(), parse_timezone_with(zone, &BTreeMap::new())?)))
+            .collect::<Result<_>>()?;
+        let formats = self
+            .formats
+            .iter()
+            .map(|(name, pattern)| {
+                Ok((
+                    name.clone(),
+                    TimeFormat::parse_with(pattern, &BTreeMap::new())?,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        let working_hours = self
+            .working_hours
+            .as_ref()
+            .map(|hours| {
+                Ok::<_, anyhow::Error>(WorkingHours {
+                    start: parse_time(&hours.start)?,
+                    end: parse_time(&hours.end)?,
+                    weekend: hours.weekend,
+                })
+            })
+            .transpose()?;
+        let holidays = self
+            .holidays
+            .iter()
+            .map(|holiday| {
+                Ok(CustomHoliday {
+                    date: NaiveDate::parse_from_str(&holiday.date, "%Y-%m-%d").map_err(|_| {
+                        anyhow!("Invalid date: {} (expected YYYY-MM-DD)", holiday.date)
+                    })?,
+                    name: holiday.name.clone(),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Settings {
+            timezone: self
+                .timezone
+                .as_deref()
+                .map(|name| parse_timezone_with(name, &team_zones))
+                .transpose()?,
+            locale: self.locale.as_deref().map(parse_locale).transpose()?,
+            format: self
+                .format
+                .as_deref()
+                .map(|format| TimeFormat::parse_with(format, &formats))
+                .transpose()?,
+            team_zones,
+            formats,
+            working_hours,
+            holidays,
+            tool_timeout: self.tool_timeout.map(Duration::from_secs),
+            tool_timeouts: self
+                .tool_timeouts
+                .iter()
+                .map(|(tool, seconds)| (tool.clone(), Duration::from_secs(*seconds)))
+                .collect(),
+            resource_updates: self.resource_updates,
+        })
+    }
+
+    pub fn selection(&self) -> Selection {
+        Selection {
+            enable: self.enable.clone(),
+            disable: self.disable.clone(),
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/now-mcp/config.toml`, or `~/.config/now-mcp/config.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(base.join("now-mcp").join("config.toml"))
+}
+
+/// Polls `path` for modifications and calls `on_change` with each newly loaded configuration.
+/// A file that fails to load is reported and otherwise ignored.
+pub fn watch(path: PathBuf, on_change: impl Fn(Config) + Send + 'static) {
+    tokio::spawn(async move {
+        let mut last_modified = modified(&path);
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            let modified = modified(&path);
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            match Config::load(&path) {
+                Ok(config) => on_change(config),
+                Err(e) => eprintln!("Error reloading config: {:#}", e),
+            }
+        }
+    });
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn parse_time(input: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(input, "%H:%M")
+        .map_err(|_| anyhow!("Invalid time: {} (expected HH:MM)", input))
+}
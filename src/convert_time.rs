@@ -0,0 +1,107 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono_tz::{OffsetName, Tz};
+use context_server::{Tool, ToolContent, ToolExecutor};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    error::invalid_arguments,
+    timezone::{default_timezone, parse_datetime, parse_timezone, Disambiguation},
+};
+
+#[derive(Deserialize)]
+struct ConvertTimeArguments {
+    time: String,
+    from_timezone: Option<String>,
+    to_timezones: Vec<String>,
+    #[serde(default)]
+    disambiguation: Disambiguation,
+}
+
+pub struct ConvertTimeTool;
+
+#[async_trait]
+impl ToolExecutor for ConvertTimeTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: ConvertTimeArguments = parse_arguments(arguments)?;
+        if arguments.to_timezones.is_empty() {
+            bail!(invalid_arguments!(
+                "Invalid arguments: to_timezones must contain at least one time zone"
+            ));
+        }
+
+        let from_timezone = match arguments.from_timezone.as_deref() {
+            Some(name) => parse_timezone(name)?,
+            None => default_timezone(),
+        };
+        let to_timezones = arguments
+            .to_timezones
+            .iter()
+            .map(|name| parse_timezone(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        let source = parse_datetime(&arguments.time, from_timezone, arguments.disambiguation)?;
+
+        let mut lines = vec![format!("Source: {}", describe(&source))];
+        lines.extend(
+            to_timezones
+                .into_iter()
+                .map(|timezone| describe(&source.with_timezone(&timezone))),
+        );
+
+        Ok(vec![ToolContent::Text {
+            text: lines.join("\n"),
+        }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "convert_time".into(),
+            description: Some(
+                "Convert a date-time from one time zone to one or more others. The time is either RFC 3339 (e.g. \"2026-03-29T14:00:00+01:00\") or a local wall-clock time (e.g. \"2026-03-29 14:00\") read in from_timezone. Local times that are ambiguous or skipped at a daylight saving transition are rejected unless disambiguation is \"earlier\" or \"later\".".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "time": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp, local date-time (YYYY-MM-DDTHH:MM[:SS]) or date (YYYY-MM-DD).",
+                    },
+                    "from_timezone": {
+                        "type": "string",
+                        "description": "IANA time zone, team zone or city of a local time. Defaults to the server's default time zone.",
+                    },
+                    "to_timezones": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "minItems": 1,
+                        "description": "IANA time zones, team zones or cities to convert to, e.g. \"Austin\".",
+                    },
+                    "disambiguation": {
+                        "type": "string",
+                        "enum": ["reject", "earlier", "later"],
+                        "description": "How to resolve a local time that is ambiguous or skipped at a daylight saving transition. Defaults to \"reject\".",
+                    },
+                },
+                "required": ["time", "to_timezones"],
+            }),
+        }
+    }
+}
+
+fn describe(datetime: &DateTime<Tz>) -> String {
+    let timezone = datetime.timezone();
+    let offset = datetime.format("%:z").to_string();
+    let abbreviation = datetime.offset().abbreviation().unwrap_or(&offset);
+
+    format!(
+        "{}: {} ({}, {})",
+        timezone.name(),
+        datetime.format("%Y-%m-%dT%H:%M:%S%:z, %A"),
+        abbreviation,
+        offset
+    )
+}
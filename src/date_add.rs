@@ -0,0 +1,177 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, Months, NaiveDateTime, SubsecRound};
+use context_server::{Tool, ToolContent, ToolExecutor};
+use indoc::formatdoc;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    error::invalid_arguments,
+    timezone::{
+        default_timezone, now, parse_datetime, parse_timezone, resolve_local, Disambiguation,
+    },
+};
+
+#[derive(Deserialize)]
+struct DateAddArguments {
+    datetime: Option<String>,
+    timezone: Option<String>,
+    #[serde(default)]
+    years: i64,
+    #[serde(default)]
+    months: i64,
+    #[serde(default)]
+    weeks: i64,
+    #[serde(default)]
+    days: i64,
+    #[serde(default)]
+    hours: i64,
+    #[serde(default)]
+    minutes: i64,
+    #[serde(default)]
+    seconds: i64,
+    #[serde(default)]
+    disambiguation: Disambiguation,
+}
+
+pub struct DateAddTool;
+
+#[async_trait]
+impl ToolExecutor for DateAddTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: DateAddArguments = parse_arguments(arguments)?;
+        let timezone = match arguments.timezone.as_deref() {
+            Some(name) => parse_timezone(name)?,
+            None => default_timezone(),
+        };
+        let base = match arguments.datetime.as_deref() {
+            Some(datetime) => parse_datetime(datetime, timezone, arguments.disambiguation)?,
+            None => now(timezone).trunc_subsecs(0),
+        };
+
+        let months = arguments
+            .years
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(arguments.months))
+            .ok_or_else(out_of_range)?;
+        let days = arguments
+            .weeks
+            .checked_mul(7)
+            .and_then(|days| days.checked_add(arguments.days))
+            .ok_or_else(out_of_range)?;
+
+        // Without a calendar shift the base instant stands as is, even inside a DST overlap.
+        let shifted = if months == 0 && days == 0 {
+            base
+        } else {
+            let local = add_months(base.naive_local(), months)?
+                .checked_add_signed(Duration::try_days(days).ok_or_else(out_of_range)?)
+                .ok_or_else(out_of_range)?;
+            resolve_local(timezone, local, arguments.disambiguation)?
+        };
+
+        let elapsed = [
+            Duration::try_hours(arguments.hours),
+            Duration::try_minutes(arguments.minutes),
+            Duration::try_seconds(arguments.seconds),
+        ]
+        .into_iter()
+        .try_fold(Duration::zero(), |total, part| total.checked_add(&part?))
+        .ok_or_else(out_of_range)?;
+        let result = shifted
+            .checked_add_signed(elapsed)
+            .ok_or_else(out_of_range)?;
+
+        Ok(vec![ToolContent::Text {
+            text: formatdoc! {"
+                Base: {}
+                Result: {}
+                Day of the week: {}
+            ", base.to_rfc3339(), result.to_rfc3339(), result.format("%A")},
+        }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "date_add".into(),
+            description: Some(
+                "Add or subtract calendar units and durations from a date-time (defaults to now). Use negative values to subtract. Years and months are applied first on the calendar: when the target month is shorter the day is clamped to its last day (2026-01-31 + 1 month = 2026-02-28), and a month-end date does not stay at month end (2026-02-28 + 1 month = 2026-03-28). Weeks and days then move the wall-clock date, keeping the time of day across daylight saving changes. Hours, minutes and seconds are added last as exact elapsed time.".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "datetime": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp, local date-time (YYYY-MM-DDTHH:MM[:SS]) or date (YYYY-MM-DD). Defaults to now.",
+                    },
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA time zone for local times and the result. Defaults to the server's default time zone.",
+                    },
+                    "years": { "type": "integer" },
+                    "months": { "type": "integer" },
+                    "weeks": { "type": "integer" },
+                    "days": { "type": "integer" },
+                    "hours": { "type": "integer" },
+                    "minutes": { "type": "integer" },
+                    "seconds": { "type": "integer" },
+                    "disambiguation": {
+                        "type": "string",
+                        "enum": ["reject", "earlier", "later"],
+                        "description": "How to resolve a local time that is ambiguous or skipped at a daylight saving transition. Defaults to \"reject\".",
+                    },
+                },
+            }),
+        }
+    }
+}
+
+/// Moves `datetime` by whole months, clamping the day to the end of a shorter month.
+pub fn add_months(datetime: NaiveDateTime, months: i64) -> Result<NaiveDateTime> {
+    let amount = Months::new(u32::try_from(months.unsigned_abs()).map_err(|_| out_of_range())?);
+    if months < 0 {
+        datetime.checked_sub_months(amount)
+    } else {
+        datetime.checked_add_months(amount)
+    }
+    .ok_or_else(out_of_range)
+}
+
+pub fn out_of_range() -> anyhow::Error {
+    invalid_arguments!("Resulting date is out of range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add(datetime: &str, months: i64) -> Result<String> {
+        let datetime = NaiveDateTime::parse_from_str(datetime, "%Y-%m-%dT%H:%M").unwrap();
+        Ok(add_months(datetime, months)?
+            .format("%Y-%m-%dT%H:%M")
+            .to_string())
+    }
+
+    #[test]
+    fn months_clamp_to_the_end_of_shorter_months() {
+        assert_eq!(add("2026-01-31T09:30", 1).unwrap(), "2026-02-28T09:30");
+        assert_eq!(add("2028-01-31T09:30", 1).unwrap(), "2028-02-29T09:30");
+        assert_eq!(add("2026-03-31T09:30", -1).unwrap(), "2026-02-28T09:30");
+        assert_eq!(add("2024-02-29T09:30", 12).unwrap(), "2025-02-28T09:30");
+    }
+
+    #[test]
+    fn month_ends_do_not_stick() {
+        assert_eq!(add("2026-02-28T00:00", 1).unwrap(), "2026-03-28T00:00");
+        assert_eq!(add("2026-10-18T00:00", 0).unwrap(), "2026-10-18T00:00");
+    }
+
+    #[test]
+    fn huge_amounts_are_out_of_range() {
+        assert!(add("2026-10-18T00:00", i64::MAX).is_err());
+        assert!(add("2026-10-18T00:00", i64::MIN).is_err());
+        assert!(add("2026-10-18T00:00", 12 * 300_000).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, SubsecRound, Weekday};
+use chrono_tz::Tz;
+use context_server::{Tool, ToolContent, ToolExecutor};
+use indoc::formatdoc;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    error::invalid_arguments,
+    timezone::{default_timezone, now, parse_datetime, parse_timezone, Disambiguation},
+};
+
+#[derive(Deserialize)]
+struct DateDiffArguments {
+    start: String,
+    end: Option<String>,
+    timezone: Option<String>,
+    #[serde(default)]
+    disambiguation: Disambiguation,
+}
+
+pub struct DateDiffTool;
+
+#[async_trait]
+impl ToolExecutor for DateDiffTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: DateDiffArguments = parse_arguments(arguments)?;
+        let timezone = match arguments.timezone.as_deref() {
+            Some(name) => parse_timezone(name)?,
+            None => default_timezone(),
+        };
+        let start = parse_datetime(&arguments.start, timezone, arguments.disambiguation)?;
+        let end = match arguments.end.as_deref() {
+            Some(end) => parse_datetime(end, timezone, arguments.disambiguation)?,
+            None => now(timezone).trunc_subsecs(0),
+        };
+
+        let (earlier, later) = if end < start {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let elapsed = (later - earlier).num_seconds();
+        let signed_elapsed = (end - start).num_seconds();
+        let calendar = calendar_difference(earlier, later)?;
+        let (weekdays, weekend_days) = count_weekdays(earlier.date_naive(), later.date_naive());
+
+        Ok(vec![ToolContent::Text {
+            text: formatdoc! {"
+                Start: {}
+                End: {}
+                Elapsed: {} seconds ({} hours, {} minutes, {} seconds)
+                Calendar difference: {} years, {} months, {} days, {} hours, {} minutes, {} seconds
+                Weekdays: {}
+                Weekend days: {}
+            ",
+                start.to_rfc3339(),
+                end.to_rfc3339(),
+                signed_elapsed,
+                elapsed / 3600, elapsed % 3600 / 60, elapsed % 60,
+                calendar.years, calendar.months, calendar.days,
+                calendar.hours, calendar.minutes, calendar.seconds,
+                weekdays,
+                weekend_days,
+            },
+        }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "date_diff".into(),
+            description: Some(
+                "Compute the difference between two date-times: the exact elapsed time, the calendar difference in years, months and days (measured on the wall clock of the given time zone, with month ends clamped like date_add), and the number of weekdays (Monday to Friday) and weekend days from the start date up to but excluding the end date. When end is before start the elapsed seconds are negative and the breakdowns describe the magnitude.".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "start": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp, local date-time (YYYY-MM-DDTHH:MM[:SS]) or date (YYYY-MM-DD).",
+                    },
+                    "end": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp, local date-time (YYYY-MM-DDTHH:MM[:SS]) or date (YYYY-MM-DD). Defaults to now.",
+                    },
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA time zone for local times and calendar arithmetic. Defaults to the server's default time zone.",
+                    },
+                    "disambiguation": {
+                        "type": "string",
+                        "enum": ["reject", "earlier", "later"],
+                        "description": "How to resolve a local time that is ambiguous or skipped at a daylight saving transition. Defaults to \"reject\".",
+                    },
+                },
+                "required": ["start"],
+            }),
+        }
+    }
+}
+
+struct CalendarDifference {
+    years: u32,
+    months: u32,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+}
+
+/// Whole months first (clamping to month end), then the remaining days and time of day on the
+/// wall clock. The wall clock runs backwards when a daylight saving change repeats an hour, so
+/// two instants in that hour fall back to the real elapsed time.
+fn calendar_difference(earlier: DateTime<Tz>, later: DateTime<Tz>) -> Result<CalendarDifference> {
+    let out_of_range = || invalid_arguments!("Date difference is out of range");
+    let elapsed = (later - earlier).num_seconds();
+    let (earlier, later) = (earlier.naive_local(), later.naive_local());
+
+    let mut months = u32::try_from(
+        (later.year() - earlier.year()) * 12 + later.month() as i32 - earlier.month() as i32,
+    )
+    .unwrap_or(0);
+    let mut anchor = earlier
+        .checked_add_months(Months::new(months))
+        .ok_or_else(out_of_range)?;
+    while months > 0 && anchor > later {
+        months -= 1;
+        anchor = earlier
+            .checked_add_months(Months::new(months))
+            .ok_or_else(out_of_range)?;
+    }
+
+    let remainder = match (later - anchor).num_seconds() {
+        remainder if remainder < 0 => elapsed,
+        remainder => remainder,
+    };
+
+    Ok(CalendarDifference {
+        years: months / 12,
+        months: months % 12,
+        days: remainder / 86400,
+        hours: remainder % 86400 / 3600,
+        minutes: remainder % 3600 / 60,
+        seconds: remainder % 60,
+    })
+}
+
+/// Counts weekdays and weekend days in `[start, end)`.
+fn count_weekdays(start: NaiveDate, end: NaiveDate) -> (i64, i64) {
+    let total = (end - start).num_days();
+    let full_weeks = total / 7;
+    let mut weekdays = full_weeks * 5;
+
+    let mut date = start + Duration::days(full_weeks * 7);
+    while date < end {
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            weekdays += 1;
+        }
+        date = date.succ_opt().unwrap_or(end);
+    }
+
+    (weekdays, total - weekdays)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_york(datetime: &str) -> DateTime<Tz> {
+        DateTime::parse_from_rfc3339(datetime)
+            .unwrap()
+            .with_timezone(&chrono_tz::America::New_York)
+    }
+
+    fn difference(earlier: &str, later: &str) -> [i64; 6] {
+        let difference = calendar_difference(new_york(earlier), new_york(later)).unwrap();
+        [
+            difference.years.into(),
+            difference.months.into(),
+            difference.days,
+            difference.hours,
+            difference.minutes,
+            difference.seconds,
+        ]
+    }
+
+    #[test]
+    fn months_are_counted_before_days() {
+        assert_eq!(
+            difference("2025-01-31T09:00:00-05:00", "2026-03-01T10:30:15-05:00"),
+            [1, 1, 1, 1, 30, 15]
+        );
+        assert_eq!(
+            difference("2026-01-31T00:00:00-05:00", "2026-02-28T00:00:00-05:00"),
+            [0, 1, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            difference("2026-03-31T12:00:00-04:00", "2026-04-30T11:00:00-04:00"),
+            [0, 0, 29, 23, 0, 0]
+        );
+    }
+
+    #[test]
+    fn days_follow_the_wall_clock_across_daylight_saving() {
+        assert_eq!(
+            difference("2026-03-07T12:00:00-05:00", "2026-03-08T12:00:00-04:00"),
+            [0, 0, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn the_repeated_hour_uses_elapsed_time() {
+        assert_eq!(
+            difference("2026-11-01T01:50:00-04:00", "2026-11-01T01:10:00-05:00"),
+            [0, 0, 0, 0, 20, 0]
+        );
+    }
+
+    #[test]
+    fn weekdays_are_counted_in_half_open_ranges() {
+        let date = |input: &str| input.parse::<NaiveDate>().unwrap();
+        assert_eq!(
+            count_weekdays(date("2026-10-12"), date("2026-10-19")),
+            (5, 2)
+        );
+        assert_eq!(
+            count_weekdays(date("2026-10-16"), date("2026-10-19")),
+            (1, 2)
+        );
+        assert_eq!(
+            count_weekdays(date("2026-10-17"), date("2026-10-17")),
+            (0, 0)
+        );
+        assert_eq!(
+            count_weekdays(date("2026-10-01"), date("2026-11-01")),
+            (22, 9)
+        );
+    }
+}
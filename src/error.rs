@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// A failure caused by the request rather than by the server, so it can be answered with its
+/// own JSON-RPC error code. Anything else is reported as an internal error.
+#[derive(Debug)]
+pub enum RequestError {
+    NotFound(String),
+    InvalidArguments(String),
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestError::NotFound(message) | RequestError::InvalidArguments(message) => {
+                f.write_str(message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+/// Like `anyhow!`, for an unknown tool, prompt or resource.
+macro_rules! not_found {
+    ($($arg:tt)*) => {
+        anyhow::Error::new($crate::error::RequestError::NotFound(format!($($arg)*)))
+    };
+}
+
+/// Like `anyhow!`, for arguments that are malformed or cannot be satisfied.
+macro_rules! invalid_arguments {
+    ($($arg:tt)*) => {
+        anyhow::Error::new($crate::error::RequestError::InvalidArguments(format!($($arg)*)))
+    };
+}
+
+pub(crate) use {invalid_arguments, not_found};
@@ -0,0 +1,231 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use anyhow::{bail, Result};
+use chrono::{
+    format::{Item, Numeric, StrftimeItems},
+    DateTime, Locale, SecondsFormat,
+};
+use chrono_tz::Tz;
+
+use crate::{error::invalid_arguments, settings::settings};
+
+pub const PRESETS: &[&str] = &[
+    "rfc2822", "rfc3339", "iso8601", "date", "time", "long", "unix",
+];
+
+/// A named preset or a strftime pattern that has already been checked to be valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeFormat {
+    Rfc2822,
+    Rfc3339,
+    Iso8601,
+    Date,
+    Time,
+    Long,
+    Unix,
+    Strftime(String),
+}
+
+impl TimeFormat {
+    /// Parses a preset, a format name from the config file, or a strftime pattern.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, &settings().formats)
+    }
+
+    /// Like [`TimeFormat::parse`], with `formats` in place of the configured named formats.
+    pub fn parse_with(input: &str, formats: &BTreeMap<String, TimeFormat>) -> Result<Self> {
+        if let Some(format) = formats.get(input) {
+            return Ok(format.clone());
+        }
+
+        let format = match input.to_ascii_lowercase().as_str() {
+            "rfc2822" => Self::Rfc2822,
+            "rfc3339" => Self::Rfc3339,
+            "iso8601" => Self::Iso8601,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "long" => Self::Long,
+            "unix" => Self::Unix,
+            _ => {
+                if input.is_empty() {
+                    bail!(invalid_arguments!("Invalid format: pattern is empty"));
+                }
+                // Some items parse but cannot be rendered (`%#z`), so render a sample instead of
+                // only looking for parse errors.
+                let sample = DateTime::UNIX_EPOCH.with_timezone(&Tz::UTC);
+                if render(&sample, input, None).is_err() {
+                    bail!(invalid_arguments!(
+                        "Invalid format: {} is not a valid strftime pattern or one of {}",
+                        input,
+                        PRESETS.join(", ")
+                    ));
+                }
+                Self::Strftime(input.to_string())
+            }
+        };
+
+        Ok(format)
+    }
+
+    /// Renders `datetime`; with a `locale`, names are localized and `long` follows the locale's
+    /// date ordering.
+    pub fn format(&self, datetime: &DateTime<Tz>, locale: Option<Locale>) -> Result<String> {
+        let formatted = match self {
+            Self::Rfc2822 => datetime.to_rfc2822(),
+            Self::Rfc3339 => datetime.to_rfc3339_opts(SecondsFormat::Secs, false),
+            Self::Iso8601 => datetime.to_rfc3339_opts(SecondsFormat::Millis, false),
+            Self::Date => datetime.format("%Y-%m-%d").to_string(),
+            Self::Time => datetime.format("%H:%M:%S").to_string(),
+            Self::Long => match locale {
+                Some(locale) => render(datetime, long_pattern(locale), Some(locale))?,
+                None => datetime.format("%A, %-d %B %Y").to_string(),
+            },
+            Self::Unix => datetime.timestamp().to_string(),
+            Self::Strftime(pattern) => render(datetime, pattern, locale)?,
+        };
+
+        Ok(formatted)
+    }
+}
+
+/// Renders a strftime `pattern`, localized with `locale`. Patterns are checked when parsed, but a
+/// locale can still expand an item into one chrono cannot render, so this fails instead of
+/// panicking like `to_string` would.
+pub fn render(datetime: &DateTime<Tz>, pattern: &str, locale: Option<Locale>) -> Result<String> {
+    let mut rendered = String::new();
+    match locale {
+        Some(locale) => write!(rendered, "{}", datetime.format_localized(pattern, locale)),
+        None => write!(rendered, "{}", datetime.format(pattern)),
+    }
+    .map_err(|_| match locale {
+        Some(locale) => invalid_arguments!(
+            "Invalid format: {} cannot be rendered in locale {}",
+            pattern,
+            locale
+        ),
+        None => invalid_arguments!("Invalid format: {} cannot be rendered", pattern),
+    })?;
+
+    Ok(rendered)
+}
+
+/// Weekday, day, month name and year, ordered like the locale's numeric date (`%x`).
+fn long_pattern(locale: Locale) -> &'static str {
+    let first = StrftimeItems::new_with_locale("%x", locale).find_map(|item| match item {
+        Item::Numeric(Numeric::Day, _) => Some(Numeric::Day),
+        Item::Numeric(Numeric::Month, _) => Some(Numeric::Month),
+        Item::Numeric(Numeric::Year | Numeric::YearMod100, _) => Some(Numeric::Year),
+        _ => None,
+    });
+    match first {
+        Some(Numeric::Year) => "%Y %B %-d %A",
+        Some(Numeric::Month) => "%A, %B %-d, %Y",
+        _ => "%A, %-d %B %Y",
+    }
+}
+
+/// Region assumed for a bare language whose `xx_XX` name is not a locale (`en` is not `en_EN`).
+const DEFAULT_REGIONS: &[(&str, &str)] = &[
+    ("ar", "SA"),
+    ("ca", "ES"),
+    ("cs", "CZ"),
+    ("da", "DK"),
+    ("el", "GR"),
+    ("en", "US"),
+    ("et", "EE"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("ja", "JP"),
+    ("ko", "KR"),
+    ("nb", "NO"),
+    ("sl", "SI"),
+    ("sv", "SE"),
+    ("uk", "UA"),
+    ("vi", "VN"),
+    ("zh", "CN"),
+];
+
+/// Parses a BCP 47 style tag such as `de-DE` or a POSIX name such as `de_DE`; a bare language
+/// such as `en` gets its usual region.
+pub fn parse_locale(input: &str) -> Result<Locale> {
+    let name = input.replace('-', "_");
+    let locale = Locale::try_from(name.as_str()).or_else(|_| match name.split_once('_') {
+        Some((language, region)) => Locale::try_from(
+            format!(
+                "{}_{}",
+                language.to_ascii_lowercase(),
+                region.to_ascii_uppercase()
+            )
+            .as_str(),
+        )
+        .map_err(|_| invalid_arguments!("Unknown locale: {}", input)),
+        None => {
+            let language = name.to_ascii_lowercase();
+            let region = DEFAULT_REGIONS
+                .iter()
+                .find(|(default, _)| *default == language)
+                .map_or_else(
+                    || language.to_ascii_uppercase(),
+                    |(_, region)| region.to_string(),
+                );
+            Locale::try_from(format!("{}_{}", language, region).as_str()).map_err(|_| {
+                invalid_arguments!(
+                    "Unknown locale: {} (use a language-REGION tag such as de-DE)",
+                    input
+                )
+            })
+        }
+    })?;
+
+    // A few locales define their date formats with items chrono cannot render (`%Ey` in th_TH,
+    // `%Oy` in fa_IR), which would fail every `%x` or `%c` rendered in them.
+    let sample = DateTime::UNIX_EPOCH.with_timezone(&Tz::UTC);
+    let renders = ["%c", "%x", "%X", "%r"].iter().all(|pattern| {
+        write!(
+            String::new(),
+            "{}",
+            sample.format_localized(pattern, locale)
+        )
+        .is_ok()
+    });
+    if !renders {
+        bail!(invalid_arguments!(
+            "Unsupported locale: {} (its date formats cannot be rendered)",
+            input
+        ));
+    }
+
+    Ok(locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_languages_get_their_usual_region() {
+        assert_eq!(parse_locale("en").unwrap(), Locale::en_US);
+        assert_eq!(parse_locale("ja").unwrap(), Locale::ja_JP);
+        assert_eq!(parse_locale("de").unwrap(), Locale::de_DE);
+        assert!(parse_locale("xx").is_err());
+    }
+
+    #[test]
+    fn locales_whose_dates_cannot_be_rendered_are_rejected() {
+        assert_eq!(parse_locale("de-de").unwrap(), Locale::de_DE);
+        assert!(parse_locale("th-TH").is_err());
+        assert!(parse_locale("fa_IR").is_err());
+    }
+
+    #[test]
+    fn long_dates_follow_the_locale_order() {
+        let datetime = DateTime::parse_from_rfc3339("2026-10-18T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Tz::UTC);
+        let long = |locale| TimeFormat::Long.format(&datetime, Some(locale)).unwrap();
+        assert_eq!(long(Locale::en_US), "Sunday, October 18, 2026");
+        assert_eq!(long(Locale::en_GB), "Sunday, 18 October 2026");
+        assert_eq!(long(Locale::de_DE), "Sonntag, 18 Oktober 2026");
+        assert_eq!(long(Locale::hu_HU), "2026 október 18 vasárnap");
+    }
+}
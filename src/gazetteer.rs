@@ -0,0 +1,288 @@
+mod cities;
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono_tz::{OffsetName, Tz};
+use context_server::{Tool, ToolContent, ToolExecutor};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments, error::invalid_arguments, settings::settings, timezone::now,
+};
+
+pub use self::cities::CITIES;
+
+/// Most places listed for a name that matches no city exactly.
+const MAX_SUGGESTIONS: usize = 10;
+
+/// A city wins over homonyms in other time zones when it is this many times as populous.
+const DOMINANCE: u32 = 10;
+
+pub struct City {
+    pub name: &'static str,
+    /// State, province or similar; empty when the country is enough to tell cities apart.
+    pub region: &'static str,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub zone: Tz,
+    /// Approximate metropolitan population in thousands.
+    pub population: u32,
+    pub aliases: &'static [&'static str],
+}
+
+impl City {
+    const fn new(
+        name: &'static str,
+        region: &'static str,
+        country: &'static str,
+        (latitude, longitude): (f64, f64),
+        zone: Tz,
+        population: u32,
+    ) -> Self {
+        Self {
+            name,
+            region,
+            country,
+            latitude,
+            longitude,
+            zone,
+            population,
+            aliases: &[],
+        }
+    }
+
+    const fn aka(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// The name with its region and country, e.g. "Portland, Maine, US".
+    pub fn label(&self) -> String {
+        [self.name, self.region, self.country]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        fold(self.name) == name || self.aliases.iter().any(|alias| fold(alias) == name)
+    }
+
+    /// Whether `qualifier` names this city's region or country code.
+    fn is_in(&self, qualifier: &str) -> bool {
+        fold(self.region) == qualifier || self.country.eq_ignore_ascii_case(qualifier)
+    }
+}
+
+/// Cities called `place`, most populous first. A place may be qualified with a region or
+/// country code, as in "Portland, Maine" or "London, CA".
+pub fn find_cities(place: &str) -> Vec<&'static City> {
+    let whole = fold(place);
+    let mut cities = CITIES
+        .iter()
+        .filter(|city| city.is_named(&whole))
+        .collect::<Vec<_>>();
+
+    if cities.is_empty() {
+        let mut parts = place.split(',').map(fold);
+        let name = parts.next().unwrap_or_default();
+        let qualifiers = parts.collect::<Vec<_>>();
+        cities = CITIES
+            .iter()
+            .filter(|city| city.is_named(&name))
+            .filter(|city| qualifiers.iter().all(|qualifier| city.is_in(qualifier)))
+            .collect();
+    }
+
+    cities.sort_by_key(|city| std::cmp::Reverse(city.population));
+    cities
+}
+
+/// Cities whose name or an alias starts with, or else contains, `place`.
+fn suggest_cities(place: &str) -> Vec<&'static City> {
+    let place = fold(place);
+    let names = |city: &City| {
+        std::iter::once(fold(city.name))
+            .chain(city.aliases.iter().map(|alias| fold(alias)))
+            .collect::<Vec<_>>()
+    };
+
+    let mut cities = CITIES
+        .iter()
+        .filter(|city| names(city).iter().any(|name| name.starts_with(&place)))
+        .collect::<Vec<_>>();
+    if cities.is_empty() {
+        cities = CITIES
+            .iter()
+            .filter(|city| names(city).iter().any(|name| name.contains(&place)))
+            .collect();
+    }
+
+    cities.sort_by_key(|city| std::cmp::Reverse(city.population));
+    cities.truncate(MAX_SUGGESTIONS);
+    cities
+}
+
+/// The time zone of the city `place`, when the gazetteer knows it. Homonyms in other zones make
+/// it ambiguous unless one of them is far more populous than the rest.
+pub fn find_zone(place: &str) -> Result<Option<Tz>> {
+    let cities = find_cities(place);
+    let Some(first) = cities.first() else {
+        return Ok(None);
+    };
+
+    let rivals = cities
+        .iter()
+        .filter(|city| city.zone != first.zone)
+        .collect::<Vec<_>>();
+    if rivals
+        .iter()
+        .any(|city| city.population * DOMINANCE > first.population)
+    {
+        bail!(invalid_arguments!(
+            "Ambiguous place: {} could be {}. Qualify it with a region or country code, or pass an IANA time zone",
+            place,
+            cities
+                .iter()
+                .map(|city| format!("{} ({})", city.label(), city.zone.name()))
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ));
+    }
+
+    Ok(Some(first.zone))
+}
+
+/// Lowercase without common diacritics, so "Sao Paulo" finds "São Paulo".
+pub fn fold(input: &str) -> String {
+    input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => 'a',
+            'ç' | 'č' | 'ć' => 'c',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ě' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' | 'ń' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            'ș' | 'ş' | 'š' | 'ś' => 's',
+            'ț' | 'ţ' => 't',
+            'ž' | 'ź' | 'ż' => 'z',
+            'ł' => 'l',
+            c => c,
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct LookupZoneArguments {
+    place: String,
+}
+
+pub struct LookupZoneTool;
+
+#[async_trait]
+impl ToolExecutor for LookupZoneTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: LookupZoneArguments = parse_arguments(arguments)?;
+        let place = arguments.place.trim();
+
+        let zone = place
+            .parse::<Tz>()
+            .ok()
+            .or_else(|| settings().team_zones.get(place).copied());
+        if let Some(zone) = zone {
+            return Ok(vec![ToolContent::Text {
+                text: format!("{} is a time zone: {}", place, describe_zone(zone)),
+            }]);
+        }
+
+        let cities = find_cities(place);
+        let text = if cities.is_empty() {
+            let suggestions = suggest_cities(place);
+            if suggestions.is_empty() {
+                bail!(invalid_arguments!("Unknown place: {}", place));
+            }
+            format!(
+                "No city is called {}. Similar places:\n{}",
+                place,
+                describe_cities(&suggestions)
+            )
+        } else {
+            let resolution = match find_zone(place) {
+                Ok(Some(zone)) => format!(
+                    "As a time zone argument, \"{}\" means {}.",
+                    place,
+                    zone.name()
+                ),
+                _ => format!(
+                    "\"{}\" is ambiguous as a time zone argument; qualify it with a region or country code, e.g. \"{}, {}\", or pass the IANA time zone.",
+                    place,
+                    cities[0].name,
+                    if cities[0].region.is_empty() {
+                        cities[0].country
+                    } else {
+                        cities[0].region
+                    }
+                ),
+            };
+            format!("{}\n{}", describe_cities(&cities), resolution)
+        };
+
+        Ok(vec![ToolContent::Text { text }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "lookup_zone".into(),
+            description: Some(
+                "Find the IANA time zone of a city from a bundled offline list of major cities, e.g. \"Lagos\" or \"Austin\". Lists every city of that name, most populous first, with its region, country, coordinates, zone and current local time, and says which zone the name resolves to when passed as a time zone argument to other tools. Qualify homonyms with a region or country code, e.g. \"Portland, Maine\" or \"London, CA\".".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "place": {
+                        "type": "string",
+                        "description": "City name, optionally followed by a region or country code, e.g. \"Hyderabad, PK\".",
+                    },
+                },
+                "required": ["place"],
+            }),
+        }
+    }
+}
+
+fn describe_cities(cities: &[&City]) -> String {
+    cities
+        .iter()
+        .map(|city| {
+            format!(
+                "{} ({:.2}, {:.2}): {}",
+                city.label(),
+                city.latitude,
+                city.longitude,
+                describe_zone(city.zone)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn describe_zone(zone: Tz) -> String {
+    let now = now(zone);
+    let offset = now.format("%:z").to_string();
+
+    format!(
+        "{} (UTC{}, {}), local time {}",
+        zone.name(),
+        offset,
+        now.offset().abbreviation().unwrap_or(&offset),
+        now.format("%Y-%m-%d %H:%M")
+    )
+}
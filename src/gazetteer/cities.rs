@@ -0,0 +1,341 @@
+//! Major cities and the time zones they observe. Populations are rough metropolitan figures in
+//! thousands, only used to rank cities that share a name.
+
+use chrono_tz::Tz;
+
+use super::{City, City as C};
+
+#[rustfmt::skip]
+pub static CITIES: &[City] = &[
+    // North America
+    C::new("New York", "New York", "US", (40.71, -74.01), Tz::America__New_York, 19500)
+        .aka(&["NYC", "New York City", "Manhattan", "Brooklyn"]),
+    C::new("Los Angeles", "California", "US", (34.05, -118.24), Tz::America__Los_Angeles, 12900)
+        .aka(&["LA"]),
+    C::new("Chicago", "Illinois", "US", (41.88, -87.63), Tz::America__Chicago, 9400),
+    C::new("Houston", "Texas", "US", (29.76, -95.37), Tz::America__Chicago, 7300),
+    C::new("Phoenix", "Arizona", "US", (33.45, -112.07), Tz::America__Phoenix, 5000),
+    C::new("Philadelphia", "Pennsylvania", "US", (39.95, -75.17), Tz::America__New_York, 6200),
+    C::new("San Antonio", "Texas", "US", (29.42, -98.49), Tz::America__Chicago, 2600),
+    C::new("San Diego", "California", "US", (32.72, -117.16), Tz::America__Los_Angeles, 3300),
+    C::new("Dallas", "Texas", "US", (32.78, -96.80), Tz::America__Chicago, 7900),
+    C::new("Austin", "Texas", "US", (30.27, -97.74), Tz::America__Chicago, 2400),
+    C::new("San Jose", "California", "US", (37.34, -121.89), Tz::America__Los_Angeles, 2000),
+    C::new("Jacksonville", "Florida", "US", (30.33, -81.66), Tz::America__New_York, 1700),
+    C::new("Fort Worth", "Texas", "US", (32.76, -97.33), Tz::America__Chicago, 950),
+    C::new("Columbus", "Ohio", "US", (39.96, -83.00), Tz::America__New_York, 2100),
+    C::new("Charlotte", "North Carolina", "US", (35.23, -80.84), Tz::America__New_York, 2800),
+    C::new("San Francisco", "California", "US", (37.77, -122.42), Tz::America__Los_Angeles, 4600)
+        .aka(&["SF"]),
+    C::new("Indianapolis", "Indiana", "US", (39.77, -86.16), Tz::America__Indiana__Indianapolis, 2100),
+    C::new("Seattle", "Washington", "US", (47.61, -122.33), Tz::America__Los_Angeles, 4000),
+    C::new("Denver", "Colorado", "US", (39.74, -104.99), Tz::America__Denver, 3000),
+    C::new("Washington", "District of Columbia", "US", (38.91, -77.04), Tz::America__New_York, 6300)
+        .aka(&["Washington DC", "Washington, D.C.", "DC"]),
+    C::new("Boston", "Massachusetts", "US", (42.36, -71.06), Tz::America__New_York, 4900),
+    C::new("Nashville", "Tennessee", "US", (36.16, -86.78), Tz::America__Chicago, 2000),
+    C::new("Detroit", "Michigan", "US", (42.33, -83.05), Tz::America__Detroit, 4300),
+    C::new("Portland", "Oregon", "US", (45.52, -122.68), Tz::America__Los_Angeles, 2500),
+    C::new("Portland", "Maine", "US", (43.66, -70.26), Tz::America__New_York, 550),
+    C::new("Las Vegas", "Nevada", "US", (36.17, -115.14), Tz::America__Los_Angeles, 2300),
+    C::new("Memphis", "Tennessee", "US", (35.15, -90.05), Tz::America__Chicago, 1300),
+    C::new("Louisville", "Kentucky", "US", (38.25, -85.76), Tz::America__Kentucky__Louisville, 1300),
+    C::new("Baltimore", "Maryland", "US", (39.29, -76.61), Tz::America__New_York, 2800),
+    C::new("Milwaukee", "Wisconsin", "US", (43.04, -87.91), Tz::America__Chicago, 1600),
+    C::new("Albuquerque", "New Mexico", "US", (35.08, -106.65), Tz::America__Denver, 900),
+    C::new("Tucson", "Arizona", "US", (32.22, -110.97), Tz::America__Phoenix, 1000),
+    C::new("Sacramento", "California", "US", (38.58, -121.49), Tz::America__Los_Angeles, 2400),
+    C::new("Kansas City", "Missouri", "US", (39.10, -94.58), Tz::America__Chicago, 2200),
+    C::new("Atlanta", "Georgia", "US", (33.75, -84.39), Tz::America__New_York, 6200),
+    C::new("Miami", "Florida", "US", (25.76, -80.19), Tz::America__New_York, 6100),
+    C::new("Orlando", "Florida", "US", (28.54, -81.38), Tz::America__New_York, 2700),
+    C::new("Tampa", "Florida", "US", (27.95, -82.46), Tz::America__New_York, 3200),
+    C::new("Melbourne", "Florida", "US", (28.08, -80.61), Tz::America__New_York, 600),
+    C::new("Minneapolis", "Minnesota", "US", (44.98, -93.27), Tz::America__Chicago, 3700),
+    C::new("New Orleans", "Louisiana", "US", (29.95, -90.07), Tz::America__Chicago, 1000),
+    C::new("Cleveland", "Ohio", "US", (41.50, -81.69), Tz::America__New_York, 2100),
+    C::new("Cincinnati", "Ohio", "US", (39.10, -84.51), Tz::America__New_York, 2200),
+    C::new("Pittsburgh", "Pennsylvania", "US", (40.44, -80.00), Tz::America__New_York, 2400),
+    C::new("St. Louis", "Missouri", "US", (38.63, -90.20), Tz::America__Chicago, 2800)
+        .aka(&["Saint Louis", "St Louis"]),
+    C::new("Salt Lake City", "Utah", "US", (40.76, -111.89), Tz::America__Denver, 1300),
+    C::new("Raleigh", "North Carolina", "US", (35.78, -78.64), Tz::America__New_York, 1500),
+    C::new("Richmond", "Virginia", "US", (37.54, -77.44), Tz::America__New_York, 1300),
+    C::new("Buffalo", "New York", "US", (42.89, -78.88), Tz::America__New_York, 1200),
+    C::new("Oklahoma City", "Oklahoma", "US", (35.47, -97.52), Tz::America__Chicago, 1400),
+    C::new("Omaha", "Nebraska", "US", (41.26, -95.93), Tz::America__Chicago, 970),
+    C::new("El Paso", "Texas", "US", (31.76, -106.49), Tz::America__Denver, 870),
+    C::new("Boise", "Idaho", "US", (43.62, -116.20), Tz::America__Boise, 800),
+    C::new("Birmingham", "Alabama", "US", (33.52, -86.80), Tz::America__Chicago, 1100),
+    C::new("Springfield", "Massachusetts", "US", (42.10, -72.59), Tz::America__New_York, 700),
+    C::new("Springfield", "Missouri", "US", (37.21, -93.29), Tz::America__Chicago, 480),
+    C::new("Springfield", "Illinois", "US", (39.78, -89.65), Tz::America__Chicago, 200),
+    C::new("Paris", "Texas", "US", (33.66, -95.56), Tz::America__Chicago, 25),
+    C::new("Anchorage", "Alaska", "US", (61.22, -149.90), Tz::America__Anchorage, 400),
+    C::new("Honolulu", "Hawaii", "US", (21.31, -157.86), Tz::Pacific__Honolulu, 1000),
+    C::new("San Juan", "", "PR", (18.47, -66.11), Tz::America__Puerto_Rico, 2000),
+    C::new("Toronto", "Ontario", "CA", (43.65, -79.38), Tz::America__Toronto, 6700),
+    C::new("Montréal", "Quebec", "CA", (45.50, -73.57), Tz::America__Toronto, 4300),
+    C::new("Vancouver", "British Columbia", "CA", (49.28, -123.12), Tz::America__Vancouver, 2700),
+    C::new("Calgary", "Alberta", "CA", (51.05, -114.07), Tz::America__Edmonton, 1600),
+    C::new("Edmonton", "Alberta", "CA", (53.55, -113.49), Tz::America__Edmonton, 1500),
+    C::new("Ottawa", "Ontario", "CA", (45.42, -75.70), Tz::America__Toronto, 1500),
+    C::new("Winnipeg", "Manitoba", "CA", (49.90, -97.14), Tz::America__Winnipeg, 850),
+    C::new("Quebec City", "Quebec", "CA", (46.81, -71.21), Tz::America__Toronto, 850)
+        .aka(&["Québec"]),
+    C::new("Halifax", "Nova Scotia", "CA", (44.65, -63.57), Tz::America__Halifax, 480),
+    C::new("St. John's", "Newfoundland and Labrador", "CA", (47.56, -52.71), Tz::America__St_Johns, 210)
+        .aka(&["St Johns", "Saint John's"]),
+    C::new("Regina", "Saskatchewan", "CA", (50.45, -104.62), Tz::America__Regina, 260),
+    C::new("London", "Ontario", "CA", (42.98, -81.25), Tz::America__Toronto, 550),
+    C::new("Kingston", "Ontario", "CA", (44.23, -76.49), Tz::America__Toronto, 170),
+    C::new("Mexico City", "", "MX", (19.43, -99.13), Tz::America__Mexico_City, 22000)
+        .aka(&["CDMX", "Ciudad de México"]),
+    C::new("Guadalajara", "Jalisco", "MX", (20.66, -103.35), Tz::America__Mexico_City, 5300),
+    C::new("Monterrey", "Nuevo León", "MX", (25.69, -100.32), Tz::America__Monterrey, 5300),
+    C::new("Tijuana", "Baja California", "MX", (32.51, -117.04), Tz::America__Tijuana, 2200),
+    C::new("Cancún", "Quintana Roo", "MX", (21.16, -86.85), Tz::America__Cancun, 900),
+    C::new("Nuuk", "", "GL", (64.18, -51.72), Tz::America__Nuuk, 20),
+    // Central America and the Caribbean
+    C::new("Guatemala City", "", "GT", (14.63, -90.51), Tz::America__Guatemala, 3000),
+    C::new("San Salvador", "", "SV", (13.69, -89.22), Tz::America__El_Salvador, 1100),
+    C::new("Tegucigalpa", "", "HN", (14.07, -87.19), Tz::America__Tegucigalpa, 1300),
+    C::new("Managua", "", "NI", (12.11, -86.24), Tz::America__Managua, 1100),
+    C::new("San José", "", "CR", (9.93, -84.08), Tz::America__Costa_Rica, 2200),
+    C::new("Panama City", "", "PA", (8.98, -79.52), Tz::America__Panama, 1900),
+    C::new("Havana", "", "CU", (23.11, -82.37), Tz::America__Havana, 2100).aka(&["La Habana"]),
+    C::new("Kingston", "", "JM", (17.97, -76.79), Tz::America__Jamaica, 1200),
+    C::new("Santo Domingo", "", "DO", (18.49, -69.93), Tz::America__Santo_Domingo, 3500),
+    // South America
+    C::new("São Paulo", "", "BR", (-23.55, -46.63), Tz::America__Sao_Paulo, 22000),
+    C::new("Rio de Janeiro", "", "BR", (-22.91, -43.17), Tz::America__Sao_Paulo, 13500)
+        .aka(&["Rio"]),
+    C::new("Belo Horizonte", "", "BR", (-19.92, -43.94), Tz::America__Sao_Paulo, 6000),
+    C::new("Brasília", "", "BR", (-15.79, -47.88), Tz::America__Sao_Paulo, 4800),
+    C::new("Porto Alegre", "", "BR", (-30.03, -51.23), Tz::America__Sao_Paulo, 4300),
+    C::new("Salvador", "Bahia", "BR", (-12.97, -38.50), Tz::America__Bahia, 3900),
+    C::new("Fortaleza", "", "BR", (-3.73, -38.53), Tz::America__Fortaleza, 4100),
+    C::new("Recife", "", "BR", (-8.05, -34.88), Tz::America__Recife, 4100),
+    C::new("Manaus", "", "BR", (-3.12, -60.02), Tz::America__Manaus, 2300),
+    C::new("Buenos Aires", "", "AR", (-34.60, -58.38), Tz::America__Argentina__Buenos_Aires, 15500),
+    C::new("Córdoba", "", "AR", (-31.42, -64.18), Tz::America__Argentina__Cordoba, 1600),
+    C::new("Santiago", "", "CL", (-33.45, -70.67), Tz::America__Santiago, 7000),
+    C::new("Lima", "", "PE", (-12.05, -77.04), Tz::America__Lima, 11000),
+    C::new("Bogotá", "", "CO", (4.71, -74.07), Tz::America__Bogota, 11500),
+    C::new("Medellín", "", "CO", (6.24, -75.58), Tz::America__Bogota, 4000),
+    C::new("Caracas", "", "VE", (10.48, -66.90), Tz::America__Caracas, 3000),
+    C::new("Valencia", "", "VE", (10.16, -68.00), Tz::America__Caracas, 1500),
+    C::new("Quito", "", "EC", (-0.18, -78.47), Tz::America__Guayaquil, 2800),
+    C::new("Guayaquil", "", "EC", (-2.17, -79.92), Tz::America__Guayaquil, 3100),
+    C::new("La Paz", "", "BO", (-16.50, -68.15), Tz::America__La_Paz, 1900),
+    C::new("Asunción", "", "PY", (-25.26, -57.58), Tz::America__Asuncion, 3000),
+    C::new("Montevideo", "", "UY", (-34.90, -56.16), Tz::America__Montevideo, 1800),
+    // Europe
+    C::new("London", "England", "GB", (51.51, -0.13), Tz::Europe__London, 9600),
+    C::new("Manchester", "England", "GB", (53.48, -2.24), Tz::Europe__London, 2800),
+    C::new("Birmingham", "England", "GB", (52.49, -1.89), Tz::Europe__London, 2900),
+    C::new("Edinburgh", "Scotland", "GB", (55.95, -3.19), Tz::Europe__London, 550),
+    C::new("Glasgow", "Scotland", "GB", (55.86, -4.25), Tz::Europe__London, 1700),
+    C::new("Perth", "Scotland", "GB", (56.40, -3.43), Tz::Europe__London, 50),
+    C::new("Belfast", "Northern Ireland", "GB", (54.60, -5.93), Tz::Europe__London, 650),
+    C::new("Cardiff", "Wales", "GB", (51.48, -3.18), Tz::Europe__London, 480),
+    C::new("Dublin", "", "IE", (53.35, -6.26), Tz::Europe__Dublin, 2000),
+    C::new("Paris", "", "FR", (48.86, 2.35), Tz::Europe__Paris, 11200),
+    C::new("Lyon", "", "FR", (45.76, 4.84), Tz::Europe__Paris, 2300),
+    C::new("Marseille", "", "FR", (43.30, 5.37), Tz::Europe__Paris, 1900),
+    C::new("Toulouse", "", "FR", (43.60, 1.44), Tz::Europe__Paris, 1400),
+    C::new("Nice", "", "FR", (43.70, 7.27), Tz::Europe__Paris, 1000),
+    C::new("Berlin", "", "DE", (52.52, 13.40), Tz::Europe__Berlin, 4500),
+    C::new("Hamburg", "", "DE", (53.55, 9.99), Tz::Europe__Berlin, 3000),
+    C::new("Munich", "Bavaria", "DE", (48.14, 11.58), Tz::Europe__Berlin, 2900).aka(&["München"]),
+    C::new("Frankfurt", "Hesse", "DE", (50.11, 8.68), Tz::Europe__Berlin, 2700)
+        .aka(&["Frankfurt am Main"]),
+    C::new("Stuttgart", "Baden-Württemberg", "DE", (48.78, 9.18), Tz::Europe__Berlin, 2800),
+    C::new("Cologne", "North Rhine-Westphalia", "DE", (50.94, 6.96), Tz::Europe__Berlin, 2100)
+        .aka(&["Köln"]),
+    C::new("Düsseldorf", "North Rhine-Westphalia", "DE", (51.23, 6.77), Tz::Europe__Berlin, 1500),
+    C::new("Amsterdam", "", "NL", (52.37, 4.90), Tz::Europe__Amsterdam, 2500),
+    C::new("Rotterdam", "", "NL", (51.92, 4.48), Tz::Europe__Amsterdam, 1000),
+    C::new("The Hague", "", "NL", (52.08, 4.30), Tz::Europe__Amsterdam, 800).aka(&["Den Haag"]),
+    C::new("Brussels", "", "BE", (50.85, 4.35), Tz::Europe__Brussels, 2100)
+        .aka(&["Bruxelles", "Brussel"]),
+    C::new("Antwerp", "", "BE", (51.22, 4.40), Tz::Europe__Brussels, 1100).aka(&["Antwerpen"]),
+    C::new("Luxembourg", "", "LU", (49.61, 6.13), Tz::Europe__Luxembourg, 130),
+    C::new("Madrid", "", "ES", (40.42, -3.70), Tz::Europe__Madrid, 6700),
+    C::new("Barcelona", "Catalonia", "ES", (41.39, 2.17), Tz::Europe__Madrid, 5600),
+    C::new("Valencia", "", "ES", (39.47, -0.38), Tz::Europe__Madrid, 1600),
+    C::new("Seville", "Andalusia", "ES", (37.39, -5.98), Tz::Europe__Madrid, 1500)
+        .aka(&["Sevilla"]),
+    C::new("Córdoba", "Andalusia", "ES", (37.88, -4.78), Tz::Europe__Madrid, 320),
+    C::new("Las Palmas", "Canary Islands", "ES", (28.12, -15.44), Tz::Atlantic__Canary, 630),
+    C::new("Lisbon", "", "PT", (38.72, -9.14), Tz::Europe__Lisbon, 2900).aka(&["Lisboa"]),
+    C::new("Porto", "", "PT", (41.15, -8.61), Tz::Europe__Lisbon, 1700).aka(&["Oporto"]),
+    C::new("Rome", "", "IT", (41.90, 12.50), Tz::Europe__Rome, 4300).aka(&["Roma"]),
+    C::new("Milan", "", "IT", (45.46, 9.19), Tz::Europe__Rome, 4300).aka(&["Milano"]),
+    C::new("Naples", "", "IT", (40.85, 14.27), Tz::Europe__Rome, 3100).aka(&["Napoli"]),
+    C::new("Turin", "", "IT", (45.07, 7.69), Tz::Europe__Rome, 1700).aka(&["Torino"]),
+    C::new("Florence", "", "IT", (43.77, 11.26), Tz::Europe__Rome, 1000).aka(&["Firenze"]),
+    C::new("Venice", "", "IT", (45.44, 12.33), Tz::Europe__Rome, 850).aka(&["Venezia"]),
+    C::new("Zürich", "", "CH", (47.38, 8.54), Tz::Europe__Zurich, 1400),
+    C::new("Geneva", "", "CH", (46.20, 6.14), Tz::Europe__Zurich, 600).aka(&["Genève"]),
+    C::new("Bern", "", "CH", (46.95, 7.45), Tz::Europe__Zurich, 420),
+    C::new("Vienna", "", "AT", (48.21, 16.37), Tz::Europe__Vienna, 2900).aka(&["Wien"]),
+    C::new("Prague", "", "CZ", (50.08, 14.44), Tz::Europe__Prague, 2700).aka(&["Praha"]),
+    C::new("Warsaw", "", "PL", (52.23, 21.01), Tz::Europe__Warsaw, 3100).aka(&["Warszawa"]),
+    C::new("Kraków", "", "PL", (50.06, 19.94), Tz::Europe__Warsaw, 1400).aka(&["Cracow"]),
+    C::new("Budapest", "", "HU", (47.50, 19.04), Tz::Europe__Budapest, 3000),
+    C::new("Copenhagen", "", "DK", (55.68, 12.57), Tz::Europe__Copenhagen, 2100)
+        .aka(&["København"]),
+    C::new("Stockholm", "", "SE", (59.33, 18.07), Tz::Europe__Stockholm, 2400),
+    C::new("Oslo", "", "NO", (59.91, 10.75), Tz::Europe__Oslo, 1600),
+    C::new("Helsinki", "", "FI", (60.17, 24.94), Tz::Europe__Helsinki, 1500),
+    C::new("Reykjavík", "", "IS", (64.15, -21.94), Tz::Atlantic__Reykjavik, 240),
+    C::new("Tallinn", "", "EE", (59.44, 24.75), Tz::Europe__Tallinn, 620),
+    C::new("Riga", "", "LV", (56.95, 24.11), Tz::Europe__Riga, 860),
+    C::new("Vilnius", "", "LT", (54.69, 25.28), Tz::Europe__Vilnius, 750),
+    C::new("Athens", "", "GR", (37.98, 23.73), Tz::Europe__Athens, 3700),
+    C::new("Istanbul", "", "TR", (41.01, 28.98), Tz::Europe__Istanbul, 15700),
+    C::new("Ankara", "", "TR", (39.93, 32.86), Tz::Europe__Istanbul, 5700),
+    C::new("Bucharest", "", "RO", (44.43, 26.10), Tz::Europe__Bucharest, 2200),
+    C::new("Sofia", "", "BG", (42.70, 23.32), Tz::Europe__Sofia, 1700),
+    C::new("Belgrade", "", "RS", (44.79, 20.45), Tz::Europe__Belgrade, 1700),
+    C::new("Zagreb", "", "HR", (45.81, 15.98), Tz::Europe__Zagreb, 1100),
+    C::new("Ljubljana", "", "SI", (46.06, 14.51), Tz::Europe__Ljubljana, 550),
+    C::new("Bratislava", "", "SK", (48.15, 17.11), Tz::Europe__Bratislava, 700),
+    C::new("Sarajevo", "", "BA", (43.86, 18.41), Tz::Europe__Sarajevo, 550),
+    C::new("Valletta", "", "MT", (35.90, 14.51), Tz::Europe__Malta, 400),
+    C::new("Nicosia", "", "CY", (35.17, 33.36), Tz::Asia__Nicosia, 330),
+    C::new("Chișinău", "", "MD", (47.01, 28.86), Tz::Europe__Chisinau, 700),
+    C::new("Kyiv", "", "UA", (50.45, 30.52), Tz::Europe__Kyiv, 3500).aka(&["Kiev"]),
+    C::new("Minsk", "", "BY", (53.90, 27.56), Tz::Europe__Minsk, 2000),
+    C::new("Moscow", "", "RU", (55.76, 37.62), Tz::Europe__Moscow, 21000),
+    C::new("Saint Petersburg", "", "RU", (59.93, 30.34), Tz::Europe__Moscow, 6200)
+        .aka(&["St. Petersburg", "St Petersburg"]),
+    C::new("Yekaterinburg", "", "RU", (56.84, 60.61), Tz::Asia__Yekaterinburg, 1500),
+    C::new("Novosibirsk", "", "RU", (55.01, 82.93), Tz::Asia__Novosibirsk, 1600),
+    C::new("Vladivostok", "", "RU", (43.12, 131.89), Tz::Asia__Vladivostok, 600),
+    // Middle East
+    C::new("Dubai", "", "AE", (25.20, 55.27), Tz::Asia__Dubai, 3600),
+    C::new("Abu Dhabi", "", "AE", (24.45, 54.38), Tz::Asia__Dubai, 1500),
+    C::new("Doha", "", "QA", (25.29, 51.53), Tz::Asia__Qatar, 2400),
+    C::new("Riyadh", "", "SA", (24.71, 46.68), Tz::Asia__Riyadh, 7500),
+    C::new("Jeddah", "", "SA", (21.49, 39.19), Tz::Asia__Riyadh, 4700),
+    C::new("Kuwait City", "", "KW", (29.38, 47.99), Tz::Asia__Kuwait, 3000),
+    C::new("Manama", "", "BH", (26.23, 50.59), Tz::Asia__Bahrain, 600),
+    C::new("Muscat", "", "OM", (23.59, 58.41), Tz::Asia__Muscat, 1600),
+    C::new("Tel Aviv", "", "IL", (32.09, 34.78), Tz::Asia__Jerusalem, 4200),
+    C::new("Jerusalem", "", "IL", (31.77, 35.21), Tz::Asia__Jerusalem, 1300),
+    C::new("Amman", "", "JO", (31.95, 35.93), Tz::Asia__Amman, 4000),
+    C::new("Beirut", "", "LB", (33.89, 35.50), Tz::Asia__Beirut, 2400),
+    C::new("Damascus", "", "SY", (33.51, 36.28), Tz::Asia__Damascus, 2500),
+    C::new("Baghdad", "", "IQ", (33.31, 44.36), Tz::Asia__Baghdad, 7500),
+    C::new("Tehran", "", "IR", (35.69, 51.39), Tz::Asia__Tehran, 9500),
+    // Africa
+    C::new("Cairo", "", "EG", (30.04, 31.24), Tz::Africa__Cairo, 22000),
+    C::new("Alexandria", "", "EG", (31.20, 29.92), Tz::Africa__Cairo, 5500),
+    C::new("Lagos", "", "NG", (6.52, 3.38), Tz::Africa__Lagos, 15000),
+    C::new("Abuja", "", "NG", (9.08, 7.40), Tz::Africa__Lagos, 3800),
+    C::new("Accra", "", "GH", (5.60, -0.19), Tz::Africa__Accra, 2600),
+    C::new("Abidjan", "", "CI", (5.36, -4.01), Tz::Africa__Abidjan, 5600),
+    C::new("Dakar", "", "SN", (14.72, -17.47), Tz::Africa__Dakar, 3300),
+    C::new("Casablanca", "", "MA", (33.57, -7.59), Tz::Africa__Casablanca, 4300),
+    C::new("Rabat", "", "MA", (34.02, -6.84), Tz::Africa__Casablanca, 2000),
+    C::new("Algiers", "", "DZ", (36.75, 3.06), Tz::Africa__Algiers, 3000),
+    C::new("Tunis", "", "TN", (36.81, 10.18), Tz::Africa__Tunis, 2700),
+    C::new("Khartoum", "", "SD", (15.50, 32.56), Tz::Africa__Khartoum, 6300),
+    C::new("Addis Ababa", "", "ET", (9.03, 38.74), Tz::Africa__Addis_Ababa, 5500),
+    C::new("Nairobi", "", "KE", (-1.29, 36.82), Tz::Africa__Nairobi, 5100),
+    C::new("Kampala", "", "UG", (0.35, 32.58), Tz::Africa__Kampala, 3800),
+    C::new("Kigali", "", "RW", (-1.95, 30.06), Tz::Africa__Kigali, 1200),
+    C::new("Dar es Salaam", "", "TZ", (-6.79, 39.21), Tz::Africa__Dar_es_Salaam, 7400),
+    C::new("Kinshasa", "", "CD", (-4.44, 15.27), Tz::Africa__Kinshasa, 17000),
+    C::new("Luanda", "", "AO", (-8.84, 13.23), Tz::Africa__Luanda, 9000),
+    C::new("Lusaka", "", "ZM", (-15.39, 28.32), Tz::Africa__Lusaka, 3000),
+    C::new("Harare", "", "ZW", (-17.83, 31.05), Tz::Africa__Harare, 2200),
+    C::new("Maputo", "", "MZ", (-25.97, 32.57), Tz::Africa__Maputo, 1200),
+    C::new("Johannesburg", "", "ZA", (-26.20, 28.05), Tz::Africa__Johannesburg, 6000),
+    C::new("Cape Town", "", "ZA", (-33.92, 18.42), Tz::Africa__Johannesburg, 4800),
+    C::new("Durban", "", "ZA", (-29.86, 31.02), Tz::Africa__Johannesburg, 3900),
+    C::new("Antananarivo", "", "MG", (-18.88, 47.51), Tz::Indian__Antananarivo, 3600),
+    C::new("Port Louis", "", "MU", (-20.16, 57.50), Tz::Indian__Mauritius, 150),
+    // Asia
+    C::new("Tokyo", "", "JP", (35.68, 139.69), Tz::Asia__Tokyo, 37000),
+    C::new("Yokohama", "", "JP", (35.44, 139.64), Tz::Asia__Tokyo, 3800),
+    C::new("Osaka", "", "JP", (34.69, 135.50), Tz::Asia__Tokyo, 19000),
+    C::new("Kyoto", "", "JP", (35.01, 135.77), Tz::Asia__Tokyo, 1500),
+    C::new("Nagoya", "", "JP", (35.18, 136.91), Tz::Asia__Tokyo, 9500),
+    C::new("Sapporo", "", "JP", (43.06, 141.35), Tz::Asia__Tokyo, 2600),
+    C::new("Fukuoka", "", "JP", (33.59, 130.40), Tz::Asia__Tokyo, 2600),
+    C::new("Seoul", "", "KR", (37.57, 126.98), Tz::Asia__Seoul, 25000),
+    C::new("Busan", "", "KR", (35.18, 129.08), Tz::Asia__Seoul, 3400),
+    C::new("Beijing", "", "CN", (39.90, 116.41), Tz::Asia__Shanghai, 21800).aka(&["Peking"]),
+    C::new("Shanghai", "", "CN", (31.23, 121.47), Tz::Asia__Shanghai, 29000),
+    C::new("Shenzhen", "", "CN", (22.54, 114.06), Tz::Asia__Shanghai, 17500),
+    C::new("Guangzhou", "", "CN", (23.13, 113.26), Tz::Asia__Shanghai, 19000).aka(&["Canton"]),
+    C::new("Chengdu", "", "CN", (30.57, 104.07), Tz::Asia__Shanghai, 9500),
+    C::new("Chongqing", "", "CN", (29.56, 106.55), Tz::Asia__Shanghai, 17000),
+    C::new("Wuhan", "", "CN", (30.59, 114.31), Tz::Asia__Shanghai, 11000),
+    C::new("Hangzhou", "", "CN", (30.27, 120.16), Tz::Asia__Shanghai, 10000),
+    C::new("Xi'an", "", "CN", (34.34, 108.94), Tz::Asia__Shanghai, 9000).aka(&["Xian"]),
+    C::new("Hong Kong", "", "HK", (22.32, 114.17), Tz::Asia__Hong_Kong, 7500),
+    C::new("Macau", "", "MO", (22.20, 113.54), Tz::Asia__Macau, 700).aka(&["Macao"]),
+    C::new("Taipei", "", "TW", (25.03, 121.57), Tz::Asia__Taipei, 7000),
+    C::new("Ulaanbaatar", "", "MN", (47.89, 106.91), Tz::Asia__Ulaanbaatar, 1700)
+        .aka(&["Ulan Bator"]),
+    C::new("Manila", "", "PH", (14.60, 120.98), Tz::Asia__Manila, 14900),
+    C::new("Hanoi", "", "VN", (21.03, 105.85), Tz::Asia__Ho_Chi_Minh, 8500),
+    C::new("Ho Chi Minh City", "", "VN", (10.82, 106.63), Tz::Asia__Ho_Chi_Minh, 9500)
+        .aka(&["Saigon"]),
+    C::new("Bangkok", "", "TH", (13.76, 100.50), Tz::Asia__Bangkok, 11000),
+    C::new("Phnom Penh", "", "KH", (11.56, 104.92), Tz::Asia__Phnom_Penh, 2300),
+    C::new("Yangon", "", "MM", (16.87, 96.20), Tz::Asia__Yangon, 5600).aka(&["Rangoon"]),
+    C::new("Kuala Lumpur", "", "MY", (3.139, 101.69), Tz::Asia__Kuala_Lumpur, 8600).aka(&["KL"]),
+    C::new("Singapore", "", "SG", (1.35, 103.82), Tz::Asia__Singapore, 5900),
+    C::new("Jakarta", "", "ID", (-6.21, 106.85), Tz::Asia__Jakarta, 34000),
+    C::new("Denpasar", "Bali", "ID", (-8.65, 115.22), Tz::Asia__Makassar, 900).aka(&["Bali"]),
+    C::new("Delhi", "", "IN", (28.70, 77.10), Tz::Asia__Kolkata, 33000).aka(&["New Delhi"]),
+    C::new("Mumbai", "Maharashtra", "IN", (19.08, 72.88), Tz::Asia__Kolkata, 21000)
+        .aka(&["Bombay"]),
+    C::new("Kolkata", "West Bengal", "IN", (22.57, 88.36), Tz::Asia__Kolkata, 15000)
+        .aka(&["Calcutta"]),
+    C::new("Bengaluru", "Karnataka", "IN", (12.97, 77.59), Tz::Asia__Kolkata, 14000)
+        .aka(&["Bangalore"]),
+    C::new("Chennai", "Tamil Nadu", "IN", (13.08, 80.27), Tz::Asia__Kolkata, 12000)
+        .aka(&["Madras"]),
+    C::new("Hyderabad", "Telangana", "IN", (17.39, 78.49), Tz::Asia__Kolkata, 11000),
+    C::new("Ahmedabad", "Gujarat", "IN", (23.02, 72.57), Tz::Asia__Kolkata, 8600),
+    C::new("Pune", "Maharashtra", "IN", (18.52, 73.86), Tz::Asia__Kolkata, 7000),
+    C::new("Karachi", "Sindh", "PK", (24.86, 67.01), Tz::Asia__Karachi, 17000),
+    C::new("Lahore", "Punjab", "PK", (31.55, 74.34), Tz::Asia__Karachi, 14000),
+    C::new("Islamabad", "", "PK", (33.68, 73.05), Tz::Asia__Karachi, 1200),
+    C::new("Hyderabad", "Sindh", "PK", (25.40, 68.37), Tz::Asia__Karachi, 2000),
+    C::new("Dhaka", "", "BD", (23.81, 90.41), Tz::Asia__Dhaka, 23000),
+    C::new("Kathmandu", "", "NP", (27.72, 85.32), Tz::Asia__Kathmandu, 1500),
+    C::new("Colombo", "", "LK", (6.93, 79.86), Tz::Asia__Colombo, 2300),
+    C::new("Malé", "", "MV", (4.18, 73.51), Tz::Indian__Maldives, 250),
+    C::new("Kabul", "", "AF", (34.56, 69.21), Tz::Asia__Kabul, 4600),
+    C::new("Tashkent", "", "UZ", (41.30, 69.24), Tz::Asia__Tashkent, 3000),
+    C::new("Almaty", "", "KZ", (43.24, 76.89), Tz::Asia__Almaty, 2200),
+    C::new("Astana", "", "KZ", (51.17, 71.45), Tz::Asia__Almaty, 1400),
+    C::new("Baku", "", "AZ", (40.41, 49.87), Tz::Asia__Baku, 2400),
+    C::new("Tbilisi", "", "GE", (41.72, 44.79), Tz::Asia__Tbilisi, 1200),
+    C::new("Yerevan", "", "AM", (40.18, 44.51), Tz::Asia__Yerevan, 1100),
+    // Oceania
+    C::new("Sydney", "New South Wales", "AU", (-33.87, 151.21), Tz::Australia__Sydney, 5400),
+    C::new("Melbourne", "Victoria", "AU", (-37.81, 144.96), Tz::Australia__Melbourne, 5200),
+    C::new("Brisbane", "Queensland", "AU", (-27.47, 153.03), Tz::Australia__Brisbane, 2700),
+    C::new("Gold Coast", "Queensland", "AU", (-28.02, 153.40), Tz::Australia__Brisbane, 700),
+    C::new("Perth", "Western Australia", "AU", (-31.95, 115.86), Tz::Australia__Perth, 2300),
+    C::new("Adelaide", "South Australia", "AU", (-34.93, 138.60), Tz::Australia__Adelaide, 1400),
+    C::new("Canberra", "Australian Capital Territory", "AU", (-35.28, 149.13), Tz::Australia__Sydney, 470),
+    C::new("Hobart", "Tasmania", "AU", (-42.88, 147.33), Tz::Australia__Hobart, 250),
+    C::new("Darwin", "Northern Territory", "AU", (-12.46, 130.84), Tz::Australia__Darwin, 150),
+    C::new("Auckland", "", "NZ", (-36.85, 174.76), Tz::Pacific__Auckland, 1700),
+    C::new("Wellington", "", "NZ", (-41.29, 174.78), Tz::Pacific__Auckland, 440),
+    C::new("Christchurch", "", "NZ", (-43.53, 172.64), Tz::Pacific__Auckland, 400),
+    C::new("Port Moresby", "", "PG", (-9.44, 147.18), Tz::Pacific__Port_Moresby, 400),
+    C::new("Suva", "", "FJ", (-18.14, 178.44), Tz::Pacific__Fiji, 180),
+    C::new("Nouméa", "", "NC", (-22.28, 166.46), Tz::Pacific__Noumea, 180),
+    C::new("Apia", "", "WS", (-13.83, -171.76), Tz::Pacific__Apia, 40),
+    C::new("Papeete", "", "PF", (-17.54, -149.57), Tz::Pacific__Tahiti, 140),
+];
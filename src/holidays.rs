@@ -0,0 +1,775 @@
+mod calendars;
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, NaiveDate, Utc, Weekday};
+use context_server::{Tool, ToolContent, ToolExecutor};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    error::invalid_arguments,
+    resource_registry::{ResourcePayload, ResourceRegistry, ResourceTemplate, TemplateProvider},
+    timezone::{default_timezone, now, parse_timezone},
+};
+
+pub use self::calendars::COUNTRIES;
+
+/// How a rule determines its date in a given year.
+#[derive(Clone, Copy)]
+pub enum Rule {
+    /// A fixed month and day.
+    Fixed(u32, u32),
+    /// The n-th weekday of a month; negative `n` counts from the end of the month.
+    NthWeekday(u32, Weekday, i8),
+    /// The last given weekday on or before a month and day.
+    WeekdayOnOrBefore(u32, u32, Weekday),
+    /// A number of days relative to Western Easter Sunday.
+    Easter(i64),
+    /// Anything the variants above cannot express.
+    Custom(fn(i32) -> Option<NaiveDate>),
+}
+
+/// Which day off is granted when a holiday falls on a weekend.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Observance {
+    /// No substitute day.
+    Actual,
+    /// Saturday moves to Friday, Sunday to Monday.
+    NearestWeekday,
+    /// Saturday and Sunday move to the next weekday that is not already a holiday.
+    NextWeekday,
+    /// Sunday moves to the next day that is not already a holiday.
+    SundayToNextFree,
+}
+
+pub struct HolidayRule {
+    name: &'static str,
+    rule: Rule,
+    observance: Observance,
+    subdivisions: &'static [&'static str],
+    since: i32,
+}
+
+impl HolidayRule {
+    const fn new(name: &'static str, rule: Rule) -> Self {
+        Self {
+            name,
+            rule,
+            observance: Observance::Actual,
+            subdivisions: &[],
+            since: i32::MIN,
+        }
+    }
+
+    const fn observed(self, observance: Observance) -> Self {
+        Self { observance, ..self }
+    }
+
+    const fn only(self, subdivisions: &'static [&'static str]) -> Self {
+        Self {
+            subdivisions,
+            ..self
+        }
+    }
+
+    const fn since(self, since: i32) -> Self {
+        Self { since, ..self }
+    }
+
+    fn applies(&self, year: i32, subdivision: Option<&str>) -> bool {
+        year >= self.since
+            && (self.subdivisions.is_empty()
+                || subdivision.is_some_and(|code| self.subdivisions.contains(&code)))
+    }
+}
+
+pub struct Country {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub subdivisions: &'static [(&'static str, &'static str)],
+    rules: &'static [HolidayRule],
+    adjust: Option<fn(&mut Vec<Holiday>)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Holiday {
+    pub name: &'static str,
+    pub date: NaiveDate,
+    pub observed: NaiveDate,
+    pub regional: bool,
+}
+
+impl Holiday {
+    fn to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "date": self.date.to_string(),
+            "observed": self.observed.to_string(),
+            "regional": self.regional,
+        })
+    }
+}
+
+pub fn find_country(code: &str) -> Result<&'static Country> {
+    COUNTRIES
+        .iter()
+        .find(|country| country.code.eq_ignore_ascii_case(code))
+        .ok_or_else(|| {
+            invalid_arguments!(
+                "Unsupported country: {} (supported: {})",
+                code,
+                COUNTRIES
+                    .iter()
+                    .map(|country| country.code)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// Normalizes `TX` or `US-TX` to the subdivision code used in the calendar.
+pub fn find_subdivision(country: &Country, code: &str) -> Result<&'static str> {
+    let code = code
+        .split_once('-')
+        .filter(|(prefix, _)| prefix.eq_ignore_ascii_case(country.code))
+        .map_or(code, |(_, code)| code);
+
+    country
+        .subdivisions
+        .iter()
+        .find(|(subdivision, _)| subdivision.eq_ignore_ascii_case(code))
+        .map(|(subdivision, _)| *subdivision)
+        .ok_or_else(|| {
+            invalid_arguments!(
+                "Unsupported subdivision for {}: {} (supported: {})",
+                country.code,
+                code,
+                country
+                    .subdivisions
+                    .iter()
+                    .map(|(subdivision, _)| *subdivision)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// Holidays observed nationwide plus those of `subdivision`, sorted by date.
+pub fn holidays(country: &Country, year: i32, subdivision: Option<&str>) -> Vec<Holiday> {
+    let mut holidays = country
+        .rules
+        .iter()
+        .filter(|rule| rule.applies(year, subdivision))
+        .filter_map(|rule| {
+            let date = rule.rule.date(year)?;
+            Some((
+                rule.observance,
+                Holiday {
+                    name: rule.name,
+                    date,
+                    observed: date,
+                    regional: !rule.subdivisions.is_empty(),
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    holidays.sort_by_key(|(_, holiday)| holiday.date);
+
+    let mut taken = holidays
+        .iter()
+        .map(|(_, holiday)| holiday.date)
+        .collect::<HashSet<_>>();
+    for (observance, holiday) in &mut holidays {
+        holiday.observed = match (*observance, holiday.date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Sat) => holiday.date - Duration::days(1),
+            (Observance::NearestWeekday, Weekday::Sun) => holiday.date + Duration::days(1),
+            (Observance::NextWeekday, Weekday::Sat | Weekday::Sun) => {
+                next_free(holiday.date, &mut taken, |date| {
+                    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+                })
+            }
+            (Observance::SundayToNextFree, Weekday::Sun) => {
+                next_free(holiday.date, &mut taken, |_| true)
+            }
+            _ => holiday.date,
+        };
+    }
+
+    let mut holidays = holidays
+        .into_iter()
+        .map(|(_, holiday)| holiday)
+        .collect::<Vec<_>>();
+    if let Some(adjust) = country.adjust {
+        adjust(&mut holidays);
+        holidays.sort_by_key(|holiday| holiday.date);
+    }
+
+    holidays
+}
+
+/// Holidays falling or observed on `date`. A holiday near New Year can be observed in the
+/// neighbouring year, as when a Saturday January 1 is observed on Friday December 31.
+pub fn holidays_on(country: &Country, date: NaiveDate, subdivision: Option<&str>) -> Vec<Holiday> {
+    (date.year() - 1..=date.year() + 1)
+        .flat_map(|year| holidays(country, year, subdivision))
+        .filter(|holiday| holiday.date == date || holiday.observed == date)
+        .collect()
+}
+
+fn next_free(
+    date: NaiveDate,
+    taken: &mut HashSet<NaiveDate>,
+    allowed: impl Fn(NaiveDate) -> bool,
+) -> NaiveDate {
+    let observed = date
+        .iter_days()
+        .skip(1)
+        .find(|candidate| allowed(*candidate) && !taken.contains(candidate))
+        .unwrap_or(date);
+    taken.insert(observed);
+    observed
+}
+
+impl Rule {
+    fn date(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            Rule::Fixed(month, day) => NaiveDate::from_ymd_opt(year, month, day),
+            Rule::NthWeekday(month, weekday, n) if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            Rule::NthWeekday(month, weekday, n) => {
+                let last = last_day_of_month(year, month)?;
+                let back = (7 + last.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                Some(last - Duration::days(back as i64 + 7 * (-(n as i64) - 1)))
+            }
+            Rule::WeekdayOnOrBefore(month, day, weekday) => {
+                let date = NaiveDate::from_ymd_opt(year, month, day)?;
+                let back = (7 + date.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                Some(date - Duration::days(back as i64))
+            }
+            Rule::Easter(offset) => Some(easter(year)? + Duration::days(offset)),
+            Rule::Custom(date) => date(year),
+        }
+    }
+}
+
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let (year, month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)?.pred_opt()
+}
+
+/// Western Easter Sunday, using the anonymous Gregorian algorithm.
+pub fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// Registers the `holidays://` templates: JSON or iCalendar (`.ics`), for any year, nationwide
+/// or with a subdivision's holidays added.
+pub fn register_resources(registry: &ResourceRegistry) -> Result<()> {
+    // The first matching template wins, and a last `{year}` would also match `TX/2027` or
+    // `2027.ics`, so the longer templates come first.
+    for calendar in [true, false] {
+        for subdivision in [true, false] {
+            registry.register_template(Arc::new(HolidaysTemplate {
+                calendar,
+                subdivision,
+            }))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Public holidays of a country for a year with observed dates, as JSON or, when `calendar` is
+/// set, as an iCalendar file with an extra event for each observed day, sent as a base64 `blob`.
+struct HolidaysTemplate {
+    calendar: bool,
+    subdivision: bool,
+}
+
+#[async_trait]
+impl TemplateProvider for HolidaysTemplate {
+    fn to_template(&self) -> ResourceTemplate {
+        let (path, example, scope, name) = if self.subdivision {
+            (
+                "{country}/{subdivision}/{year}",
+                "US/TX/2027",
+                "Nationwide and regional public holidays of a country's subdivision",
+                "Regional public holidays",
+            )
+        } else {
+            (
+                "{country}/{year}",
+                "US/2027",
+                "Nationwide public holidays of a country",
+                "Public holidays",
+            )
+        };
+        let (extension, kind, name, mime_type) = if self.calendar {
+            (
+                ".ics",
+                "as an iCalendar file",
+                format!("{} calendar", name),
+                "text/calendar",
+            )
+        } else {
+            (
+                "",
+                "with observed dates",
+                name.to_string(),
+                "application/json",
+            )
+        };
+
+        ResourceTemplate {
+            uri_template: format!("holidays://{}{}", path, extension),
+            name,
+            description: Some(format!(
+                "{} for a year {}, e.g. holidays://{}{}",
+                scope, kind, example, extension
+            )),
+            mime_type: Some(mime_type.into()),
+        }
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<ResourcePayload> {
+        let country = find_country(&variables["country"])?;
+        let subdivision = match variables.get("subdivision") {
+            Some(subdivision) => Some(find_subdivision(country, subdivision)?),
+            None => None,
+        };
+        let year = variables["year"]
+            .parse::<i32>()
+            .ok()
+            .filter(|year| (1..=9999).contains(year))
+            .ok_or_else(|| invalid_arguments!("Invalid year: {}", variables["year"]))?;
+        let holidays = holidays(country, year, subdivision);
+
+        if self.calendar {
+            // Served as a file, CRLF line endings intact, rather than as text to display.
+            return Ok(ResourcePayload::Blob(
+                to_icalendar(country, &holidays).into_bytes(),
+            ));
+        }
+
+        Ok(ResourcePayload::Text(serde_json::to_string_pretty(
+            &holidays.iter().map(Holiday::to_json).collect::<Vec<_>>(),
+        )?))
+    }
+}
+
+fn to_icalendar(country: &Country, holidays: &[Holiday]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:-//{}//Holidays//EN", env!("CARGO_PKG_NAME")),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{} public holidays", escape_text(country.name)),
+    ];
+
+    for (index, holiday) in holidays.iter().enumerate() {
+        let mut events = vec![(holiday.date, holiday.name.to_string(), "")];
+        if holiday.observed != holiday.date {
+            events.push((
+                holiday.observed,
+                format!("{} (observed)", holiday.name),
+                "-observed",
+            ));
+        }
+
+        for (date, summary, suffix) in events {
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!(
+                    "UID:{}-{}-{}{}@{}",
+                    country.code,
+                    holiday.date.year(),
+                    index,
+                    suffix,
+                    env!("CARGO_PKG_NAME")
+                ),
+                format!("DTSTAMP:{}", stamp),
+                format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")),
+                format!(
+                    "DTEND;VALUE=DATE:{}",
+                    (date + Duration::days(1)).format("%Y%m%d")
+                ),
+                format!("SUMMARY:{}", escape_text(&summary)),
+                "TRANSP:TRANSPARENT".to_string(),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines.join("\r\n") + "\r\n"
+}
+
+/// Escapes an iCalendar TEXT value (RFC 5545, section 3.3.11).
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+}
+
+#[derive(Deserialize)]
+struct HolidaysArguments {
+    country: Option<String>,
+    subdivision: Option<String>,
+    year: Option<i32>,
+    date: Option<String>,
+    timezone: Option<String>,
+}
+
+pub struct HolidaysTool;
+
+#[async_trait]
+impl ToolExecutor for HolidaysTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: HolidaysArguments = parse_arguments(arguments)?;
+
+        let Some(country) = arguments.country.as_deref() else {
+            let text = COUNTRIES
+                .iter()
+                .map(|country| {
+                    format!(
+                        "{}: {} (subdivisions: {})",
+                        country.code,
+                        country.name,
+                        if country.subdivisions.is_empty() {
+                            "none".to_string()
+                        } else {
+                            country
+                                .subdivisions
+                                .iter()
+                                .map(|(code, name)| format!("{} {}", code, name))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        }
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Ok(vec![ToolContent::Text { text }]);
+        };
+
+        let country = find_country(country)?;
+        let subdivision = arguments
+            .subdivision
+            .as_deref()
+            .map(|code| find_subdivision(country, code))
+            .transpose()?;
+        let region = match subdivision {
+            Some(subdivision) => format!("{}-{}", country.code, subdivision),
+            None => country.code.to_string(),
+        };
+
+        if let Some(date) = arguments.date.as_deref() {
+            if arguments.year.is_some() {
+                bail!(invalid_arguments!(
+                    "Invalid arguments: pass either date or year, not both"
+                ));
+            }
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| invalid_arguments!("Invalid date: {} (expected YYYY-MM-DD)", date))?;
+            let matches = holidays_on(country, date, subdivision)
+                .into_iter()
+                .map(|holiday| {
+                    if holiday.date == date {
+                        holiday.name.to_string()
+                    } else {
+                        format!("{} (observed, falls on {})", holiday.name, holiday.date)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let text = if matches.is_empty() {
+                format!(
+                    "{} ({}) is not a public holiday in {}",
+                    date,
+                    date.format("%A"),
+                    region
+                )
+            } else {
+                format!(
+                    "{} ({}) is a public holiday in {}: {}",
+                    date,
+                    date.format("%A"),
+                    region,
+                    matches.join(", ")
+                )
+            };
+            return Ok(vec![ToolContent::Text { text }]);
+        }
+
+        let year = match arguments.year {
+            Some(year) => year,
+            None => {
+                let timezone = match arguments.timezone.as_deref() {
+                    Some(name) => parse_timezone(name)?,
+                    None => default_timezone(),
+                };
+                now(timezone).year()
+            }
+        };
+
+        let mut lines = vec![format!("Public holidays in {} for {}:", region, year)];
+        lines.extend(holidays(country, year, subdivision).iter().map(|holiday| {
+            let mut line = format!(
+                "{} ({}): {}",
+                holiday.date,
+                holiday.date.format("%A"),
+                holiday.name
+            );
+            if holiday.observed != holiday.date {
+                line.push_str(&format!(", observed {}", holiday.observed));
+            }
+            if holiday.regional {
+                line.push_str(" [regional]");
+            }
+            line
+        }));
+        if subdivision.is_none() && !country.subdivisions.is_empty() {
+            lines.push(
+                "Regional holidays are omitted; pass a subdivision to include them.".to_string(),
+            );
+        }
+
+        Ok(vec![ToolContent::Text {
+            text: lines.join("\n"),
+        }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "holidays".into(),
+            description: Some(
+                "Look up public holidays from a bundled offline calendar. With country and year, lists the holidays of that year with their observed (substitute) dates. With country and date, checks whether that date is a holiday. Without country, lists the supported countries and subdivisions.".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "country": {
+                        "type": "string",
+                        "description": "ISO 3166-1 alpha-2 country code, e.g. \"US\" or \"DE\".",
+                    },
+                    "subdivision": {
+                        "type": "string",
+                        "description": "Subdivision code to include regional holidays, e.g. \"BY\" or \"DE-BY\".",
+                    },
+                    "year": {
+                        "type": "integer",
+                        "description": "Year to list. Defaults to the current year.",
+                    },
+                    "date": {
+                        "type": "string",
+                        "description": "Date (YYYY-MM-DD) to check instead of listing a year.",
+                    },
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA time zone used to determine the current year. Defaults to the server's default time zone.",
+                    },
+                },
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(input: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(input, "%Y-%m-%d").unwrap()
+    }
+
+    /// The date and observed date of the holiday called `name`, or with `name` as the English
+    /// name in parentheses.
+    fn find(code: &str, year: i32, subdivision: Option<&str>, name: &str) -> (String, String) {
+        let country = find_country(code).unwrap();
+        let holiday = holidays(country, year, subdivision)
+            .into_iter()
+            .find(|holiday| holiday.name == name || holiday.name.ends_with(&format!("({})", name)))
+            .unwrap_or_else(|| panic!("no {} in {} {}", name, code, year));
+        (holiday.date.to_string(), holiday.observed.to_string())
+    }
+
+    fn pair(date: &str, observed: &str) -> (String, String) {
+        (date.to_string(), observed.to_string())
+    }
+
+    #[test]
+    fn easter_sunday() {
+        for (year, expected) in [
+            (1818, "1818-03-22"),
+            (1943, "1943-04-25"),
+            (1961, "1961-04-02"),
+            (2000, "2000-04-23"),
+            (2019, "2019-04-21"),
+            (2024, "2024-03-31"),
+            (2025, "2025-04-20"),
+            (2026, "2026-04-05"),
+            (2038, "2038-04-25"),
+        ] {
+            assert_eq!(easter(year), Some(date(expected)), "{}", year);
+        }
+    }
+
+    #[test]
+    fn weekday_rules() {
+        assert_eq!(
+            find("US", 2026, None, "Martin Luther King Jr. Day").0,
+            "2026-01-19"
+        );
+        assert_eq!(find("US", 2026, None, "Memorial Day").0, "2026-05-25");
+        assert_eq!(find("US", 2026, None, "Thanksgiving Day").0, "2026-11-26");
+        assert_eq!(find("GB", 2026, None, "Good Friday").0, "2026-04-03");
+        assert_eq!(
+            find("GB", 2026, Some("ENG"), "Easter Monday").0,
+            "2026-04-06"
+        );
+    }
+
+    #[test]
+    fn us_moves_weekend_holidays_to_the_nearest_weekday() {
+        assert_eq!(
+            find("US", 2020, None, "Independence Day"),
+            pair("2020-07-04", "2020-07-03")
+        );
+        assert_eq!(
+            find("US", 2021, None, "Independence Day"),
+            pair("2021-07-04", "2021-07-05")
+        );
+        assert_eq!(
+            find("US", 2022, None, "Christmas Day"),
+            pair("2022-12-25", "2022-12-26")
+        );
+        assert_eq!(
+            find("US", 2022, None, "New Year's Day"),
+            pair("2022-01-01", "2021-12-31")
+        );
+        assert!(holidays(find_country("US").unwrap(), 2020, None)
+            .iter()
+            .all(|holiday| !holiday.name.starts_with("Juneteenth")));
+    }
+
+    #[test]
+    fn gb_moves_weekend_holidays_to_the_next_free_weekday() {
+        assert_eq!(
+            find("GB", 2021, None, "Christmas Day"),
+            pair("2021-12-25", "2021-12-27")
+        );
+        assert_eq!(
+            find("GB", 2021, None, "Boxing Day"),
+            pair("2021-12-26", "2021-12-28")
+        );
+        assert_eq!(
+            find("GB", 2022, None, "Christmas Day"),
+            pair("2022-12-25", "2022-12-27")
+        );
+        assert_eq!(
+            find("GB", 2022, None, "Boxing Day"),
+            pair("2022-12-26", "2022-12-26")
+        );
+    }
+
+    #[test]
+    fn jp_moves_sunday_holidays_to_the_next_free_day() {
+        assert_eq!(
+            find("JP", 2023, None, "New Year's Day"),
+            pair("2023-01-01", "2023-01-02")
+        );
+        assert_eq!(
+            find("JP", 2019, None, "Children's Day"),
+            pair("2019-05-05", "2019-05-06")
+        );
+        assert_eq!(
+            find("JP", 2020, None, "Constitution Memorial Day"),
+            pair("2020-05-03", "2020-05-06")
+        );
+        assert_eq!(find("JP", 2026, None, "Citizens' Holiday").0, "2026-09-22");
+        assert_eq!(find("JP", 2026, None, "Vernal Equinox Day").0, "2026-03-20");
+        assert_eq!(
+            find("JP", 2026, None, "Autumnal Equinox Day").0,
+            "2026-09-23"
+        );
+    }
+
+    #[test]
+    fn observed_days_cross_new_year() {
+        let us = find_country("US").unwrap();
+        let names = |day| {
+            holidays_on(us, date(day), None)
+                .iter()
+                .map(|holiday| holiday.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("2021-12-31"), ["New Year's Day"]);
+        assert_eq!(names("2022-01-01"), ["New Year's Day"]);
+        assert!(names("2022-01-03").is_empty());
+    }
+
+    #[test]
+    fn subdivisions() {
+        let gb = find_country("gb").unwrap();
+        assert_eq!(find_subdivision(gb, "GB-SCT").unwrap(), "SCT");
+        assert_eq!(find_subdivision(gb, "wls").unwrap(), "WLS");
+        assert!(find_subdivision(gb, "TX").is_err());
+
+        let national = holidays(gb, 2026, None);
+        assert!(national.iter().all(|holiday| !holiday.regional));
+        assert!(holidays(gb, 2026, Some("SCT"))
+            .iter()
+            .any(|holiday| holiday.name == "St Andrew's Day"));
+    }
+
+    #[test]
+    fn icalendar_escapes_text_and_uses_crlf() {
+        let calendar = to_icalendar(
+            find_country("US").unwrap(),
+            &[Holiday {
+                name: "Test; with, commas",
+                date: date("2027-07-04"),
+                observed: date("2027-07-05"),
+                regional: false,
+            }],
+        );
+
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+        assert!(calendar.contains("SUMMARY:Test\\; with\\, commas\r\n"));
+        assert!(calendar.contains("UID:US-2027-0-observed@now-mcp\r\n"));
+        assert!(!calendar.replace("\r\n", "").contains('\n'));
+    }
+}
@@ -0,0 +1,468 @@
+//! Rule-based public holiday calendars. One-off holidays (jubilees, state funerals) are not
+//! included, and rules only apply from the year they were introduced when that is recent.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use super::{
+    Country, Holiday, HolidayRule as H,
+    Observance::{NearestWeekday, NextWeekday, SundayToNextFree},
+    Rule::{Custom, Easter, Fixed, NthWeekday, WeekdayOnOrBefore},
+};
+
+pub static COUNTRIES: &[Country] = &[
+    Country {
+        code: "US",
+        name: "United States",
+        subdivisions: &[
+            ("AK", "Alaska"),
+            ("CA", "California"),
+            ("HI", "Hawaii"),
+            ("MA", "Massachusetts"),
+            ("ME", "Maine"),
+            ("TX", "Texas"),
+        ],
+        rules: &[
+            H::new("New Year's Day", Fixed(1, 1)).observed(NearestWeekday),
+            H::new("Martin Luther King Jr. Day", NthWeekday(1, Weekday::Mon, 3)),
+            H::new("Washington's Birthday", NthWeekday(2, Weekday::Mon, 3)),
+            H::new("Texas Independence Day", Fixed(3, 2)).only(&["TX"]),
+            H::new("Prince Jonah Kuhio Kalanianaole Day", Fixed(3, 26)).only(&["HI"]),
+            H::new("Seward's Day", NthWeekday(3, Weekday::Mon, -1)).only(&["AK"]),
+            H::new("César Chávez Day", Fixed(3, 31)).only(&["CA"]),
+            H::new("Patriots' Day", NthWeekday(4, Weekday::Mon, 3)).only(&["MA", "ME"]),
+            H::new("San Jacinto Day", Fixed(4, 21)).only(&["TX"]),
+            H::new("Memorial Day", NthWeekday(5, Weekday::Mon, -1)),
+            H::new("King Kamehameha I Day", Fixed(6, 11)).only(&["HI"]),
+            H::new("Juneteenth National Independence Day", Fixed(6, 19))
+                .observed(NearestWeekday)
+                .since(2021),
+            H::new("Independence Day", Fixed(7, 4)).observed(NearestWeekday),
+            H::new("Labor Day", NthWeekday(9, Weekday::Mon, 1)),
+            H::new("Columbus Day", NthWeekday(10, Weekday::Mon, 2)),
+            H::new("Alaska Day", Fixed(10, 18)).only(&["AK"]),
+            H::new("Veterans Day", Fixed(11, 11)).observed(NearestWeekday),
+            H::new("Thanksgiving Day", NthWeekday(11, Weekday::Thu, 4)),
+            H::new("Christmas Day", Fixed(12, 25)).observed(NearestWeekday),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "CA",
+        name: "Canada",
+        subdivisions: &[
+            ("AB", "Alberta"),
+            ("BC", "British Columbia"),
+            ("MB", "Manitoba"),
+            ("NB", "New Brunswick"),
+            ("NL", "Newfoundland and Labrador"),
+            ("NS", "Nova Scotia"),
+            ("ON", "Ontario"),
+            ("PE", "Prince Edward Island"),
+            ("QC", "Quebec"),
+            ("SK", "Saskatchewan"),
+        ],
+        rules: &[
+            H::new("New Year's Day", Fixed(1, 1)).observed(NextWeekday),
+            H::new("Family Day", NthWeekday(2, Weekday::Mon, 3))
+                .only(&["AB", "BC", "NB", "ON", "SK"]),
+            H::new("Louis Riel Day", NthWeekday(2, Weekday::Mon, 3)).only(&["MB"]),
+            H::new("Heritage Day", NthWeekday(2, Weekday::Mon, 3)).only(&["NS"]),
+            H::new("Islander Day", NthWeekday(2, Weekday::Mon, 3)).only(&["PE"]),
+            H::new("Good Friday", Easter(-2)),
+            H::new("Easter Monday", Easter(1)).only(&["QC"]),
+            H::new("Victoria Day", WeekdayOnOrBefore(5, 24, Weekday::Mon)),
+            H::new("Saint-Jean-Baptiste Day", Fixed(6, 24))
+                .observed(NextWeekday)
+                .only(&["QC"]),
+            H::new("Canada Day", Fixed(7, 1)).observed(NextWeekday),
+            H::new("Labour Day", NthWeekday(9, Weekday::Mon, 1)),
+            H::new("National Day for Truth and Reconciliation", Fixed(9, 30))
+                .observed(NextWeekday)
+                .since(2021),
+            H::new("Thanksgiving", NthWeekday(10, Weekday::Mon, 2)),
+            H::new("Remembrance Day", Fixed(11, 11)).observed(NextWeekday),
+            H::new("Christmas Day", Fixed(12, 25)).observed(NextWeekday),
+            H::new("Boxing Day", Fixed(12, 26)).observed(NextWeekday),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "MX",
+        name: "Mexico",
+        subdivisions: &[],
+        rules: &[
+            H::new("Año Nuevo", Fixed(1, 1)),
+            H::new("Día de la Constitución", NthWeekday(2, Weekday::Mon, 1)),
+            H::new("Natalicio de Benito Juárez", NthWeekday(3, Weekday::Mon, 3)),
+            H::new("Día del Trabajo", Fixed(5, 1)),
+            H::new("Día de la Independencia", Fixed(9, 16)),
+            H::new("Día de la Revolución", NthWeekday(11, Weekday::Mon, 3)),
+            H::new("Navidad", Fixed(12, 25)),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "BR",
+        name: "Brazil",
+        subdivisions: &[],
+        rules: &[
+            H::new("Confraternização Universal", Fixed(1, 1)),
+            H::new("Sexta-feira Santa", Easter(-2)),
+            H::new("Tiradentes", Fixed(4, 21)),
+            H::new("Dia do Trabalho", Fixed(5, 1)),
+            H::new("Independência do Brasil", Fixed(9, 7)),
+            H::new("Nossa Senhora Aparecida", Fixed(10, 12)),
+            H::new("Finados", Fixed(11, 2)),
+            H::new("Proclamação da República", Fixed(11, 15)),
+            H::new(
+                "Dia Nacional de Zumbi e da Consciência Negra",
+                Fixed(11, 20),
+            )
+            .since(2024),
+            H::new("Natal", Fixed(12, 25)),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "GB",
+        name: "United Kingdom",
+        subdivisions: &[
+            ("ENG", "England"),
+            ("NIR", "Northern Ireland"),
+            ("SCT", "Scotland"),
+            ("WLS", "Wales"),
+        ],
+        rules: &[
+            H::new("New Year's Day", Fixed(1, 1)).observed(NextWeekday),
+            H::new("2nd January", Fixed(1, 2))
+                .observed(NextWeekday)
+                .only(&["SCT"]),
+            H::new("St Patrick's Day", Fixed(3, 17))
+                .observed(NextWeekday)
+                .only(&["NIR"]),
+            H::new("Good Friday", Easter(-2)),
+            H::new("Easter Monday", Easter(1)).only(&["ENG", "NIR", "WLS"]),
+            H::new("Early May bank holiday", NthWeekday(5, Weekday::Mon, 1)),
+            H::new("Spring bank holiday", NthWeekday(5, Weekday::Mon, -1)),
+            H::new("Battle of the Boyne", Fixed(7, 12))
+                .observed(NextWeekday)
+                .only(&["NIR"]),
+            H::new("Summer bank holiday", NthWeekday(8, Weekday::Mon, 1)).only(&["SCT"]),
+            H::new("Summer bank holiday", NthWeekday(8, Weekday::Mon, -1))
+                .only(&["ENG", "NIR", "WLS"]),
+            H::new("St Andrew's Day", Fixed(11, 30))
+                .observed(NextWeekday)
+                .only(&["SCT"]),
+            H::new("Christmas Day", Fixed(12, 25)).observed(NextWeekday),
+            H::new("Boxing Day", Fixed(12, 26)).observed(NextWeekday),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "IE",
+        name: "Ireland",
+        subdivisions: &[],
+        rules: &[
+            H::new("New Year's Day", Fixed(1, 1)).observed(NextWeekday),
+            H::new("St Brigid's Day", Custom(st_brigids_day)).since(2023),
+            H::new("St Patrick's Day", Fixed(3, 17)).observed(NextWeekday),
+            H::new("Easter Monday", Easter(1)),
+            H::new("May Day", NthWeekday(5, Weekday::Mon, 1)),
+            H::new("June Bank Holiday", NthWeekday(6, Weekday::Mon, 1)),
+            H::new("August Bank Holiday", NthWeekday(8, Weekday::Mon, 1)),
+            H::new("October Bank Holiday", NthWeekday(10, Weekday::Mon, -1)),
+            H::new("Christmas Day", Fixed(12, 25)).observed(NextWeekday),
+            H::new("St Stephen's Day", Fixed(12, 26)).observed(NextWeekday),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "FR",
+        name: "France",
+        subdivisions: &[],
+        rules: &[
+            H::new("Jour de l'an", Fixed(1, 1)),
+            H::new("Lundi de Pâques", Easter(1)),
+            H::new("Fête du Travail", Fixed(5, 1)),
+            H::new("Victoire 1945", Fixed(5, 8)),
+            H::new("Ascension", Easter(39)),
+            H::new("Lundi de Pentecôte", Easter(50)),
+            H::new("Fête nationale", Fixed(7, 14)),
+            H::new("Assomption", Fixed(8, 15)),
+            H::new("Toussaint", Fixed(11, 1)),
+            H::new("Armistice 1918", Fixed(11, 11)),
+            H::new("Noël", Fixed(12, 25)),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "DE",
+        name: "Germany",
+        subdivisions: &[
+            ("BB", "Brandenburg"),
+            ("BE", "Berlin"),
+            ("BW", "Baden-Württemberg"),
+            ("BY", "Bavaria"),
+            ("HB", "Bremen"),
+            ("HE", "Hesse"),
+            ("HH", "Hamburg"),
+            ("MV", "Mecklenburg-Vorpommern"),
+            ("NI", "Lower Saxony"),
+            ("NW", "North Rhine-Westphalia"),
+            ("RP", "Rhineland-Palatinate"),
+            ("SH", "Schleswig-Holstein"),
+            ("SL", "Saarland"),
+            ("SN", "Saxony"),
+            ("ST", "Saxony-Anhalt"),
+            ("TH", "Thuringia"),
+        ],
+        rules: &[
+            H::new("Neujahr", Fixed(1, 1)),
+            H::new("Heilige Drei Könige", Fixed(1, 6)).only(&["BW", "BY", "ST"]),
+            H::new("Internationaler Frauentag", Fixed(3, 8))
+                .only(&["BE"])
+                .since(2019),
+            H::new("Internationaler Frauentag", Fixed(3, 8))
+                .only(&["MV"])
+                .since(2023),
+            H::new("Karfreitag", Easter(-2)),
+            H::new("Ostermontag", Easter(1)),
+            H::new("Tag der Arbeit", Fixed(5, 1)),
+            H::new("Christi Himmelfahrt", Easter(39)),
+            H::new("Pfingstmontag", Easter(50)),
+            H::new("Fronleichnam", Easter(60)).only(&["BW", "BY", "HE", "NW", "RP", "SL"]),
+            H::new("Mariä Himmelfahrt", Fixed(8, 15)).only(&["SL"]),
+            H::new("Weltkindertag", Fixed(9, 20))
+                .only(&["TH"])
+                .since(2019),
+            H::new("Tag der Deutschen Einheit", Fixed(10, 3)),
+            H::new("Reformationstag", Fixed(10, 31))
+                .only(&["BB", "HB", "HH", "MV", "NI", "SH", "SN", "ST", "TH"]),
+            H::new("Allerheiligen", Fixed(11, 1)).only(&["BW", "BY", "NW", "RP", "SL"]),
+            H::new("Buß- und Bettag", WeekdayOnOrBefore(11, 22, Weekday::Wed)).only(&["SN"]),
+            H::new("Erster Weihnachtstag", Fixed(12, 25)),
+            H::new("Zweiter Weihnachtstag", Fixed(12, 26)),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "IT",
+        name: "Italy",
+        subdivisions: &[],
+        rules: &[
+            H::new("Capodanno", Fixed(1, 1)),
+            H::new("Epifania", Fixed(1, 6)),
+            H::new("Lunedì dell'Angelo", Easter(1)),
+            H::new("Festa della Liberazione", Fixed(4, 25)),
+            H::new("Festa del Lavoro", Fixed(5, 1)),
+            H::new("Festa della Repubblica", Fixed(6, 2)),
+            H::new("Ferragosto", Fixed(8, 15)),
+            H::new("San Francesco d'Assisi", Fixed(10, 4)).since(2026),
+            H::new("Ognissanti", Fixed(11, 1)),
+            H::new("Immacolata Concezione", Fixed(12, 8)),
+            H::new("Natale", Fixed(12, 25)),
+            H::new("Santo Stefano", Fixed(12, 26)),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "ES",
+        name: "Spain",
+        subdivisions: &[("CT", "Catalonia"), ("MD", "Madrid")],
+        rules: &[
+            H::new("Año Nuevo", Fixed(1, 1)),
+            H::new("Epifanía del Señor", Fixed(1, 6)),
+            H::new("Jueves Santo", Easter(-3)).only(&["MD"]),
+            H::new("Viernes Santo", Easter(-2)),
+            H::new("Lunes de Pascua", Easter(1)).only(&["CT"]),
+            H::new("Fiesta del Trabajo", Fixed(5, 1)),
+            H::new("Fiesta de la Comunidad de Madrid", Fixed(5, 2)).only(&["MD"]),
+            H::new("Sant Joan", Fixed(6, 24)).only(&["CT"]),
+            H::new("Asunción de la Virgen", Fixed(8, 15)),
+            H::new("Diada Nacional de Catalunya", Fixed(9, 11)).only(&["CT"]),
+            H::new("Fiesta Nacional de España", Fixed(10, 12)),
+            H::new("Todos los Santos", Fixed(11, 1)),
+            H::new("Día de la Constitución", Fixed(12, 6)),
+            H::new("Inmaculada Concepción", Fixed(12, 8)),
+            H::new("Navidad", Fixed(12, 25)),
+            H::new("Sant Esteve", Fixed(12, 26)).only(&["CT"]),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "NL",
+        name: "Netherlands",
+        subdivisions: &[],
+        rules: &[
+            H::new("Nieuwjaarsdag", Fixed(1, 1)),
+            H::new("Goede Vrijdag", Easter(-2)),
+            H::new("Eerste Paasdag", Easter(0)),
+            H::new("Tweede Paasdag", Easter(1)),
+            H::new("Koningsdag", Custom(kings_day)).since(2014),
+            H::new("Bevrijdingsdag", Fixed(5, 5)),
+            H::new("Hemelvaartsdag", Easter(39)),
+            H::new("Eerste Pinksterdag", Easter(49)),
+            H::new("Tweede Pinksterdag", Easter(50)),
+            H::new("Eerste Kerstdag", Fixed(12, 25)),
+            H::new("Tweede Kerstdag", Fixed(12, 26)),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "AU",
+        name: "Australia",
+        subdivisions: &[
+            ("ACT", "Australian Capital Territory"),
+            ("NSW", "New South Wales"),
+            ("NT", "Northern Territory"),
+            ("QLD", "Queensland"),
+            ("SA", "South Australia"),
+            ("TAS", "Tasmania"),
+            ("VIC", "Victoria"),
+            ("WA", "Western Australia"),
+        ],
+        rules: &[
+            H::new("New Year's Day", Fixed(1, 1)).observed(NextWeekday),
+            H::new("Australia Day", Fixed(1, 26)).observed(NextWeekday),
+            H::new("Labour Day", NthWeekday(3, Weekday::Mon, 1)).only(&["WA"]),
+            H::new("Labour Day", NthWeekday(3, Weekday::Mon, 2)).only(&["VIC"]),
+            H::new("Eight Hours Day", NthWeekday(3, Weekday::Mon, 2)).only(&["TAS"]),
+            H::new("Good Friday", Easter(-2)),
+            H::new("Easter Saturday", Easter(-1)).only(&["ACT", "NSW", "NT", "QLD", "SA", "VIC"]),
+            H::new("Easter Monday", Easter(1)),
+            H::new("Anzac Day", Fixed(4, 25)),
+            H::new("Labour Day", NthWeekday(5, Weekday::Mon, 1)).only(&["QLD"]),
+            H::new("May Day", NthWeekday(5, Weekday::Mon, 1)).only(&["NT"]),
+            H::new("Western Australia Day", NthWeekday(6, Weekday::Mon, 1)).only(&["WA"]),
+            H::new("King's Birthday", NthWeekday(6, Weekday::Mon, 2))
+                .only(&["ACT", "NSW", "NT", "SA", "TAS", "VIC"]),
+            H::new("Labour Day", NthWeekday(10, Weekday::Mon, 1)).only(&["ACT", "NSW", "SA"]),
+            H::new("King's Birthday", NthWeekday(10, Weekday::Mon, 1)).only(&["QLD"]),
+            H::new("Melbourne Cup Day", NthWeekday(11, Weekday::Tue, 1)).only(&["VIC"]),
+            H::new("Christmas Day", Fixed(12, 25)).observed(NextWeekday),
+            H::new("Boxing Day", Fixed(12, 26)).observed(NextWeekday),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "NZ",
+        name: "New Zealand",
+        subdivisions: &[],
+        rules: &[
+            H::new("New Year's Day", Fixed(1, 1)).observed(NextWeekday),
+            H::new("Day after New Year's Day", Fixed(1, 2)).observed(NextWeekday),
+            H::new("Waitangi Day", Fixed(2, 6)).observed(NextWeekday),
+            H::new("Good Friday", Easter(-2)),
+            H::new("Easter Monday", Easter(1)),
+            H::new("Anzac Day", Fixed(4, 25)).observed(NextWeekday),
+            H::new("King's Birthday", NthWeekday(6, Weekday::Mon, 1)),
+            H::new("Labour Day", NthWeekday(10, Weekday::Mon, 4)),
+            H::new("Christmas Day", Fixed(12, 25)).observed(NextWeekday),
+            H::new("Boxing Day", Fixed(12, 26)).observed(NextWeekday),
+        ],
+        adjust: None,
+    },
+    Country {
+        code: "JP",
+        name: "Japan",
+        subdivisions: &[],
+        rules: &[
+            H::new("元日 (New Year's Day)", Fixed(1, 1)).observed(SundayToNextFree),
+            H::new(
+                "成人の日 (Coming of Age Day)",
+                NthWeekday(1, Weekday::Mon, 2),
+            ),
+            H::new("建国記念の日 (National Foundation Day)", Fixed(2, 11))
+                .observed(SundayToNextFree),
+            H::new("天皇誕生日 (Emperor's Birthday)", Fixed(2, 23))
+                .observed(SundayToNextFree)
+                .since(2020),
+            H::new("春分の日 (Vernal Equinox Day)", Custom(vernal_equinox))
+                .observed(SundayToNextFree),
+            H::new("昭和の日 (Showa Day)", Fixed(4, 29)).observed(SundayToNextFree),
+            H::new("憲法記念日 (Constitution Memorial Day)", Fixed(5, 3))
+                .observed(SundayToNextFree),
+            H::new("みどりの日 (Greenery Day)", Fixed(5, 4)).observed(SundayToNextFree),
+            H::new("こどもの日 (Children's Day)", Fixed(5, 5)).observed(SundayToNextFree),
+            H::new("海の日 (Marine Day)", NthWeekday(7, Weekday::Mon, 3)),
+            H::new("山の日 (Mountain Day)", Fixed(8, 11))
+                .observed(SundayToNextFree)
+                .since(2016),
+            H::new(
+                "敬老の日 (Respect for the Aged Day)",
+                NthWeekday(9, Weekday::Mon, 3),
+            ),
+            H::new("秋分の日 (Autumnal Equinox Day)", Custom(autumnal_equinox))
+                .observed(SundayToNextFree),
+            H::new("スポーツの日 (Sports Day)", NthWeekday(10, Weekday::Mon, 2)),
+            H::new("文化の日 (Culture Day)", Fixed(11, 3)).observed(SundayToNextFree),
+            H::new("勤労感謝の日 (Labour Thanksgiving Day)", Fixed(11, 23))
+                .observed(SundayToNextFree),
+        ],
+        adjust: Some(citizens_holidays),
+    },
+];
+
+/// The first Monday of February, or 1 February when it falls on a Friday.
+fn st_brigids_day(year: i32) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, 2, 1)?;
+    if first.weekday() == Weekday::Fri {
+        Some(first)
+    } else {
+        NaiveDate::from_weekday_of_month_opt(year, 2, Weekday::Mon, 1)
+    }
+}
+
+/// 27 April, or 26 April when the 27th is a Sunday.
+fn kings_day(year: i32) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(year, 4, 27)?;
+    if date.weekday() == Weekday::Sun {
+        date.pred_opt()
+    } else {
+        Some(date)
+    }
+}
+
+/// Approximation published by the National Astronomical Observatory, valid 1980–2099.
+fn vernal_equinox(year: i32) -> Option<NaiveDate> {
+    equinox(year, 3, 20.8431)
+}
+
+fn autumnal_equinox(year: i32) -> Option<NaiveDate> {
+    equinox(year, 9, 23.2488)
+}
+
+fn equinox(year: i32, month: u32, base: f64) -> Option<NaiveDate> {
+    if !(1980..=2099).contains(&year) {
+        return None;
+    }
+    let elapsed = (year - 1980) as f64;
+    let day = (base + 0.242194 * elapsed - (elapsed / 4.0).floor()).floor();
+    NaiveDate::from_ymd_opt(year, month, day as u32)
+}
+
+/// A day sandwiched between two national holidays is itself a holiday.
+fn citizens_holidays(holidays: &mut Vec<Holiday>) {
+    let dates = holidays
+        .iter()
+        .map(|holiday| holiday.date)
+        .collect::<Vec<_>>();
+    let taken = holidays
+        .iter()
+        .flat_map(|holiday| [holiday.date, holiday.observed])
+        .collect::<Vec<_>>();
+
+    for date in &dates {
+        let between = *date + Duration::days(1);
+        let after = *date + Duration::days(2);
+        if dates.contains(&after) && !taken.contains(&between) && between.weekday() != Weekday::Sun
+        {
+            holidays.push(Holiday {
+                name: "国民の休日 (Citizens' Holiday)",
+                date: between,
+                observed: between,
+                regional: false,
+            });
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::post,
+    Json, Router,
+};
+use futures::stream;
+use parking_lot::Mutex;
+use tokio::{
+    net::TcpListener,
+    sync::broadcast::error::RecvError,
+    time::{interval, sleep},
+};
+use uuid::Uuid;
+
+use crate::{
+    rpc::{self, Message},
+    session::Session,
+    shutdown::{Shutdown, SHUTDOWN_TIMEOUT},
+    ContextServerState,
+};
+
+const ENDPOINT: &str = "/mcp";
+
+/// Names the session an HTTP client got from `initialize`, on every later request.
+const SESSION_HEADER: &str = "mcp-session-id";
+
+/// How long a session may go without requests or an open stream before it is ended, for
+/// clients that go away without a DELETE.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Most sessions kept at once; further `initialize` requests are refused.
+const MAX_SESSIONS: usize = 1024;
+
+struct HttpState {
+    server: Arc<ContextServerState>,
+    /// One session per client, keyed by the id sent in [`SESSION_HEADER`].
+    sessions: Mutex<HashMap<String, HttpSession>>,
+    allowed_origins: Vec<String>,
+    shutdown: Shutdown,
+}
+
+struct HttpSession {
+    session: Arc<Session>,
+    last_seen: Instant,
+}
+
+/// Serves MCP's streamable HTTP transport on `address` until shutdown, which then waits up to
+/// [`SHUTDOWN_TIMEOUT`] for in-flight requests.
+///
+/// Requests are POSTed as JSON-RPC messages to `/mcp`. Responses come back as JSON, or as a
+/// single-event SSE stream when the client only accepts `text/event-stream`. A GET on `/mcp`
+/// opens an SSE stream of `notifications/resources/updated` for subscribed resources.
+///
+/// A successful `initialize` response carries an `Mcp-Session-Id` header. Every later request
+/// must send it back, and a DELETE with it ends the session; so does [`SESSION_IDLE_TIMEOUT`]
+/// without requests.
+pub async fn serve(
+    server: Arc<ContextServerState>,
+    address: SocketAddr,
+    allowed_origins: Vec<String>,
+    shutdown: Shutdown,
+) -> Result<()> {
+    let state = Arc::new(HttpState {
+        server,
+        sessions: Default::default(),
+        allowed_origins,
+        shutdown: shutdown.clone(),
+    });
+    tokio::spawn({
+        let state = state.clone();
+        let mut shutdown = shutdown.clone();
+        async move {
+            let mut ticks = interval(SESSION_IDLE_TIMEOUT / 10);
+            loop {
+                tokio::select! {
+                    _ = shutdown.requested() => break,
+                    _ = ticks.tick() => state.end_idle_sessions(),
+                }
+            }
+        }
+    });
+
+    let app = Router::new()
+        .route(
+            ENDPOINT,
+            post(handle_post).get(handle_get).delete(handle_delete),
+        )
+        .with_state(state);
+
+    let listener = TcpListener::bind(address).await?;
+    eprintln!("Listening on http://{}{}", listener.local_addr()?, ENDPOINT);
+    let mut graceful = shutdown.clone();
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move { graceful.requested().await });
+    let mut deadline = shutdown;
+    let deadline = async move {
+        deadline.requested().await;
+        sleep(SHUTDOWN_TIMEOUT).await;
+    };
+
+    tokio::select! {
+        result = server => Ok(result?),
+        _ = deadline => bail!("Timed out waiting for in-flight requests to finish"),
+    }
+}
+
+async fn handle_post(
+    State(state): State<Arc<HttpState>>,
+    headers: HeaderMap,
+    body: String,
+) -> Response {
+    if !state.allows_origin(&headers) {
+        return (StatusCode::FORBIDDEN, "Origin not allowed").into_response();
+    }
+
+    let message = match rpc::parse_message(&body) {
+        Ok(message) => message,
+        Err(error) => return (StatusCode::BAD_REQUEST, Json(error)).into_response(),
+    };
+
+    let initialize = is_initialize(&message);
+    let (id, session) = if initialize {
+        (Uuid::new_v4().to_string(), Arc::new(Session::default()))
+    } else {
+        match state.session(&headers) {
+            Ok(session) => session,
+            Err(rejection) => return rejection.into_response(),
+        }
+    };
+
+    let response = session.handle(&state.server, message).await;
+    // A failed `initialize` leaves no session behind.
+    if initialize {
+        if response
+            .as_ref()
+            .and_then(|response| response.get("result"))
+            .is_none()
+        {
+            return Json(response).into_response();
+        }
+        if let Err(rejection) = state.add_session(id.clone(), session) {
+            return rejection.into_response();
+        }
+    }
+
+    let mut response = match response {
+        Some(response) if accepts_only_event_stream(&headers) => {
+            let event = Event::default().event("message").json_data(&response);
+            Sse::new(stream::once(async move { event })).into_response()
+        }
+        Some(response) => Json(response).into_response(),
+        None => StatusCode::ACCEPTED.into_response(),
+    };
+    if let Ok(id) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(SESSION_HEADER, id);
+    }
+
+    response
+}
+
+/// Ends the session named in the request headers.
+async fn handle_delete(State(state): State<Arc<HttpState>>, headers: HeaderMap) -> Response {
+    if !state.allows_origin(&headers) {
+        return (StatusCode::FORBIDDEN, "Origin not allowed").into_response();
+    }
+
+    match state.session(&headers) {
+        Ok((id, session)) => {
+            state.sessions.lock().remove(&id);
+            session.close(&state.server);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(rejection) => rejection.into_response(),
+    }
+}
+
+/// Streams resource update notifications until the client disconnects or the server shuts down.
+async fn handle_get(State(state): State<Arc<HttpState>>, headers: HeaderMap) -> Response {
+    if !state.allows_origin(&headers) {
+        return (StatusCode::FORBIDDEN, "Origin not allowed").into_response();
+    }
+
+    let session = match state.session(&headers) {
+        Ok((_, session)) => session,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    let updates = state.server.updates.subscribe();
+    let shutdown = state.shutdown.clone();
+    let events = stream::unfold(
+        (session, updates, shutdown),
+        |(session, mut updates, mut shutdown)| async move {
+            loop {
+                let uri = tokio::select! {
+                    _ = shutdown.requested() => return None,
+                    uri = updates.recv() => match uri {
+                        Ok(uri) => uri,
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return None,
+                    },
+                };
+                if let Some(notification) = session.notification_for(&uri) {
+                    let event = Event::default().event("message").json_data(&notification);
+                    return Some((event, (session, updates, shutdown)));
+                }
+            }
+        },
+    );
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+impl HttpState {
+    /// The session named in the request headers, or the response refusing the request: 400
+    /// without a session id, 404 for one that is unknown or already ended.
+    fn session(
+        &self,
+        headers: &HeaderMap,
+    ) -> Result<(String, Arc<Session>), (StatusCode, &'static str)> {
+        let Some(id) = headers.get(SESSION_HEADER).and_then(|id| id.to_str().ok()) else {
+            return Err((StatusCode::BAD_REQUEST, "Missing Mcp-Session-Id header"));
+        };
+
+        match self.sessions.lock().get_mut(id) {
+            Some(entry) => {
+                entry.last_seen = Instant::now();
+                Ok((id.to_string(), entry.session.clone()))
+            }
+            None => Err((StatusCode::NOT_FOUND, "Unknown session")),
+        }
+    }
+
+    /// Keeps a newly initialized session, refusing it with 503 when [`MAX_SESSIONS`] are
+    /// already active.
+    fn add_session(
+        &self,
+        id: String,
+        session: Arc<Session>,
+    ) -> Result<(), (StatusCode, &'static str)> {
+        self.end_idle_sessions();
+
+        let mut sessions = self.sessions.lock();
+        if sessions.len() >= MAX_SESSIONS {
+            session.close(&self.server);
+            return Err((StatusCode::SERVICE_UNAVAILABLE, "Too many sessions"));
+        }
+        sessions.insert(
+            id,
+            HttpSession {
+                session,
+                last_seen: Instant::now(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Ends sessions idle for [`SESSION_IDLE_TIMEOUT`]. A session still held elsewhere, by a
+    /// request in progress or an open event stream, is never idle.
+    fn end_idle_sessions(&self) {
+        let mut idle = Vec::new();
+        self.sessions.lock().retain(|_, entry| {
+            let active = Arc::strong_count(&entry.session) > 1
+                || entry.last_seen.elapsed() < SESSION_IDLE_TIMEOUT;
+            if !active {
+                idle.push(entry.session.clone());
+            }
+            active
+        });
+
+        for session in idle {
+            session.close(&self.server);
+        }
+    }
+
+    /// Browsers always send `Origin`; only loopback pages and the configured origins may call
+    /// in, which guards against DNS rebinding. Non-browser clients send no `Origin` at all.
+    fn allows_origin(&self, headers: &HeaderMap) -> bool {
+        let Some(origin) = headers
+            .get(header::ORIGIN)
+            .and_then(|origin| origin.to_str().ok())
+        else {
+            return true;
+        };
+
+        let authority = origin.split_once("://").map_or(origin, |(_, rest)| rest);
+        let host = match authority.strip_prefix('[') {
+            Some(bracketed) => bracketed.split(']').next(),
+            None => authority.split(':').next(),
+        };
+
+        matches!(host, Some("localhost" | "127.0.0.1" | "::1"))
+            || self.allowed_origins.iter().any(|allowed| allowed == origin)
+    }
+}
+
+/// Whether `message` opens a new session; `initialize` may not be part of a batch.
+fn is_initialize(message: &Message) -> bool {
+    matches!(message, Message::Single(Ok(request)) if request.method == "initialize")
+}
+
+fn accepts_only_event_stream(headers: &HeaderMap) -> bool {
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .unwrap_or_default();
+
+    accept.contains("text/event-stream") && !accept.contains("application/json")
+}
@@ -1,118 +1,751 @@
+mod arguments;
+mod business_days;
+mod completion;
+mod config;
+mod convert_time;
+mod date_add;
+mod date_diff;
+mod error;
+mod format;
+mod gazetteer;
+mod holidays;
+mod http;
+mod parse_date;
 mod prompt_registry;
 mod resource_registry;
+mod rpc;
+mod session;
+mod settings;
+mod shutdown;
+#[cfg(unix)]
+mod socket;
+mod time_resources;
+mod timezone;
 mod tool_registry;
+mod uri_template;
 
-use std::sync::Arc;
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
-use chrono::Datelike;
+use chrono::{Datelike, Locale, Offset, SecondsFormat, SubsecRound};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use clap::Parser;
 use context_server::{
     ComputedPrompt, ContextServer, ContextServerRpcRequest, ContextServerRpcResponse, Prompt,
-    PromptContent, PromptExecutor, PromptMessage, PromptRole, Tool, ToolContent, ToolExecutor,
+    PromptArgument, PromptContent, PromptExecutor, PromptMessage, PromptRole, Tool, ToolContent,
+    ToolExecutor,
 };
 use indoc::formatdoc;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::{
+    io::{self, AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    sync::{broadcast, mpsc},
+    time::{sleep_until, Instant},
+};
 
 use crate::{
-    prompt_registry::PromptRegistry, resource_registry::ResourceRegistry,
+    arguments::parse_arguments,
+    business_days::BusinessDaysTool,
+    config::Config,
+    convert_time::ConvertTimeTool,
+    date_add::DateAddTool,
+    date_diff::DateDiffTool,
+    format::{parse_locale, render, TimeFormat},
+    gazetteer::LookupZoneTool,
+    holidays::HolidaysTool,
+    parse_date::ParseDateTool,
+    prompt_registry::PromptRegistry,
+    resource_registry::ResourceRegistry,
+    session::Session,
+    settings::{set_settings, settings, ResourceUpdates, Selection, Settings},
+    shutdown::{Shutdown, SHUTDOWN_TIMEOUT},
+    timezone::{default_timezone, now, parse_timezone, parse_timezone_with},
     tool_registry::ToolRegistry,
 };
 
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Default IANA time zone, used when a call does not pass one.
+    #[arg(long, env = "NOW_MCP_TIMEZONE")]
+    timezone: Option<String>,
+
+    /// Default locale for weekday and month names, e.g. de-DE.
+    #[arg(long, env = "NOW_MCP_LOCALE")]
+    locale: Option<String>,
+
+    /// Default output format for `now`: a strftime pattern or a preset name.
+    #[arg(long, env = "NOW_MCP_FORMAT")]
+    format: Option<String>,
+
+    /// Only register these tools and prompts (comma-separated names).
+    #[arg(long, env = "NOW_MCP_ENABLE", value_delimiter = ',')]
+    enable: Vec<String>,
+
+    /// Never register these tools and prompts (comma-separated names).
+    #[arg(long, env = "NOW_MCP_DISABLE", value_delimiter = ',')]
+    disable: Vec<String>,
+
+    /// Seconds a tool call may run before it fails [default: 30].
+    #[arg(long, env = "NOW_MCP_TOOL_TIMEOUT")]
+    tool_timeout: Option<u64>,
+
+    /// How often subscribers to time resources are notified [default: minute].
+    #[arg(long, env = "NOW_MCP_RESOURCE_UPDATES", value_enum)]
+    resource_updates: Option<ResourceUpdates>,
+
+    /// Config file, watched for changes. Defaults to now-mcp/config.toml in the XDG config dir.
+    #[arg(long, env = "NOW_MCP_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Serve MCP over streamable HTTP on this address (e.g. 127.0.0.1:3000) instead of stdio.
+    #[arg(long, env = "NOW_MCP_HTTP")]
+    http: Option<SocketAddr>,
+
+    /// Serve MCP on this Unix socket instead of stdio, one session per connection.
+    #[cfg(unix)]
+    #[arg(long, env = "NOW_MCP_SOCKET", conflicts_with = "http")]
+    socket: Option<PathBuf>,
+
+    /// Browser origins allowed to call the HTTP endpoint, besides localhost (comma-separated).
+    #[arg(long, env = "NOW_MCP_ALLOW_ORIGIN", value_delimiter = ',')]
+    allow_origin: Vec<String>,
+}
+
+impl Cli {
+    /// The config file settings, with any flags given on the command line taking precedence.
+    /// Flags may name the file's team zones and formats.
+    fn settings(&self, config: &Config) -> Result<Settings> {
+        let mut settings = config.settings()?;
+        if let Some(timezone) = self.timezone.as_deref() {
+            settings.timezone = Some(parse_timezone_with(timezone, &settings.team_zones)?);
+        }
+        if let Some(locale) = self.locale.as_deref() {
+            settings.locale = Some(parse_locale(locale)?);
+        }
+        if let Some(format) = self.format.as_deref() {
+            settings.format = Some(TimeFormat::parse_with(format, &settings.formats)?);
+        }
+        if let Some(seconds) = self.tool_timeout {
+            settings.tool_timeout = Some(Duration::from_secs(seconds));
+        }
+        if let Some(resource_updates) = self.resource_updates {
+            settings.resource_updates = resource_updates;
+        }
+
+        Ok(settings)
+    }
+
+    fn selection(&self, config: &Config) -> Selection {
+        let mut selection = config.selection();
+        if !self.enable.is_empty() {
+            selection.enable = self.enable.clone();
+        }
+        if !self.disable.is_empty() {
+            selection.disable = self.disable.clone();
+        }
+
+        selection
+    }
+}
+
 struct ContextServerState {
     rpc: ContextServer,
+    tools: Vec<Arc<dyn ToolExecutor>>,
+    prompts: Vec<Arc<dyn PromptExecutor>>,
+    tool_registry: Arc<ToolRegistry>,
+    prompt_registry: Arc<PromptRegistry>,
+    resource_registry: Arc<ResourceRegistry>,
+    /// URIs of resources whose content changed, for sessions subscribed to them.
+    updates: broadcast::Sender<String>,
 }
 
 impl ContextServerState {
     fn new() -> Result<Self> {
         let resource_registry = Arc::new(ResourceRegistry::default());
+        holidays::register_resources(&resource_registry)?;
+        time_resources::register_templates(&resource_registry)?;
 
         let tool_registry = Arc::new(ToolRegistry::default());
-        tool_registry.register(Arc::new(NowTool));
-
         let prompt_registry = Arc::new(PromptRegistry::default());
-        prompt_registry.register(Arc::new(NowPrompt));
 
         Ok(Self {
             rpc: ContextServer::builder()
                 .with_server_info((env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")))
-                .with_resources(resource_registry)
-                .with_tools(tool_registry)
-                .with_prompts(prompt_registry)
+                .with_resources(resource_registry.clone())
+                .with_tools(tool_registry.clone())
+                .with_prompts(prompt_registry.clone())
                 .build()?,
+            tools: vec![
+                Arc::new(NowTool),
+                Arc::new(ConvertTimeTool),
+                Arc::new(DateAddTool),
+                Arc::new(DateDiffTool),
+                Arc::new(BusinessDaysTool),
+                Arc::new(HolidaysTool),
+                Arc::new(ParseDateTool),
+                Arc::new(LookupZoneTool),
+            ],
+            prompts: vec![Arc::new(NowPrompt)],
+            tool_registry,
+            prompt_registry,
+            resource_registry,
+            updates: broadcast::channel(64).0,
         })
     }
 
+    /// Applies the settings and tool selection in place. Nothing changes if either is invalid.
+    fn configure(&self, cli: &Cli, config: &Config) -> Result<()> {
+        let settings = cli.settings(config)?;
+        let selection = cli.selection(config);
+
+        let known = self
+            .tools
+            .iter()
+            .map(|tool| tool.to_tool().name)
+            .chain(self.prompts.iter().map(|prompt| prompt.name().to_string()))
+            .collect::<Vec<_>>();
+        for name in selection.enable.iter().chain(&selection.disable) {
+            if !known.contains(name) {
+                bail!(
+                    "Unknown tool or prompt: {} (available: {})",
+                    name,
+                    known.join(", ")
+                );
+            }
+        }
+
+        set_settings(settings);
+        time_resources::register_resources(&self.resource_registry);
+
+        for tool in &self.tools {
+            let name = tool.to_tool().name;
+            if selection.allows(&name) {
+                self.tool_registry.register(tool.clone());
+            } else {
+                self.tool_registry.unregister(&name);
+            }
+        }
+
+        for prompt in &self.prompts {
+            if selection.allows(prompt.name()) {
+                self.prompt_registry.register(prompt.clone());
+            } else {
+                self.prompt_registry.unregister(prompt.name());
+            }
+        }
+
+        Ok(())
+    }
+
     async fn process_request(
         &self,
         request: ContextServerRpcRequest,
     ) -> Result<Option<ContextServerRpcResponse>> {
         self.rpc.handle_incoming_message(request).await
     }
+
+    /// Dispatches a parsed request. Failures become error responses tied to the request id;
+    /// notifications never get a response.
+    async fn dispatch(&self, request: ContextServerRpcRequest) -> Option<Value> {
+        let id = request.id.clone();
+        if !rpc::is_known_method(&request.method) {
+            return id.map(|id| {
+                rpc::error_response(
+                    id,
+                    rpc::METHOD_NOT_FOUND,
+                    format!("Method not found: {}", request.method),
+                )
+            });
+        }
+
+        let method = request.method.clone();
+        let called_tool = (method == "tools/call")
+            .then(|| {
+                request
+                    .params
+                    .as_ref()?
+                    .get("name")?
+                    .as_str()
+                    .map(str::to_string)
+            })
+            .flatten();
+        let response = match method.as_str() {
+            // Not handled by `ContextServer`, which predates resource templates and completion.
+            "resources/templates/list" => Ok(id.clone().map(|id| {
+                rpc::result_response(
+                    id,
+                    json!({ "resourceTemplates": self.resource_registry.list_templates() }),
+                )
+            })),
+            "completion/complete" => completion::complete(self, request.params)
+                .map(|result| id.clone().map(|id| rpc::result_response(id, result))),
+            _ => self
+                .process_request(request)
+                .await
+                .and_then(|response| Ok(response.map(serde_json::to_value).transpose()?)),
+        };
+        let response = response.map(|response| {
+            response.map(|mut response| {
+                match method.as_str() {
+                    "initialize" => {
+                        if let Some(capabilities) = response.pointer_mut("/result/capabilities") {
+                            capabilities["completions"] = json!({});
+                        }
+                    }
+                    // `Tool` has no output schema and tool results no structured content, so
+                    // both are added to the `now` tool here.
+                    "tools/list" => {
+                        if let Some(tools) = response
+                            .pointer_mut("/result/tools")
+                            .and_then(Value::as_array_mut)
+                        {
+                            for tool in tools.iter_mut().filter(|tool| tool["name"] == "now") {
+                                tool["outputSchema"] = time_info_schema();
+                            }
+                        }
+                    }
+                    "tools/call" if called_tool.as_deref() == Some("now") => {
+                        let structured = response
+                            .pointer("/result/content/1/text")
+                            .and_then(Value::as_str)
+                            .and_then(|text| serde_json::from_str::<Value>(text).ok());
+                        if let Some(structured) = structured {
+                            response["result"]["structuredContent"] = structured;
+                        }
+                    }
+                    _ => {}
+                }
+                response
+            })
+        });
+
+        match (response, id) {
+            (Ok(response), _) => response,
+            (Err(e), Some(id)) => Some(rpc::failure_response(id, &e)),
+            (Err(e), None) => {
+                eprintln!("Error handling {}: {:#}", method, e);
+                None
+            }
+        }
+    }
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let state = ContextServerState::new()?;
-    let mut stdin = BufReader::new(io::stdin()).lines();
-    let mut stdout = io::stdout();
+fn main() -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    let result = runtime.block_on(run(Cli::parse()));
+    // Stdin is read on a blocking thread that may still be waiting for input; don't wait on it.
+    runtime.shutdown_background();
 
-    while let Some(line) = stdin.next_line().await? {
-        let request: ContextServerRpcRequest = match serde_json::from_str(&line) {
-            Ok(req) => req,
-            Err(e) => {
-                eprintln!("Error parsing request: {}", e);
-                continue;
+    result
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    let config_path = cli.config.clone().or_else(config::default_path);
+    let config = match config_path.as_deref() {
+        Some(path) => Config::load(path)?,
+        None => Config::default(),
+    };
+
+    let state = Arc::new(ContextServerState::new()?);
+    state.configure(&cli, &config)?;
+    let shutdown = Shutdown::listen()?;
+    time_resources::spawn_updates(state.resource_registry.clone(), state.updates.clone());
+
+    let http = cli.http;
+    #[cfg(unix)]
+    let socket = cli.socket.clone();
+    let allowed_origins = cli.allow_origin.clone();
+
+    if let Some(path) = config_path {
+        let state = state.clone();
+        config::watch(path, move |config| {
+            if let Err(e) = state.configure(&cli, &config) {
+                eprintln!("Error applying config: {:#}", e);
             }
-        };
+        });
+    }
 
-        if let Some(response) = state.process_request(request).await? {
-            let response_json = serde_json::to_string(&response)?;
-            stdout.write_all(response_json.as_bytes()).await?;
-            stdout.write_all(b"\n").await?;
-            stdout.flush().await?;
+    #[cfg(unix)]
+    if let Some(path) = socket {
+        return socket::serve(state, path, shutdown).await;
+    }
+
+    match http {
+        Some(address) => http::serve(state, address, allowed_origins, shutdown).await,
+        None => serve_lines(state, BufReader::new(io::stdin()), io::stdout(), shutdown).await,
+    }
+}
+
+/// Newline-delimited JSON-RPC, used for stdio and for each Unix socket connection.
+///
+/// Every message is handled on its own task and responses are written as they complete, so a
+/// slow tool does not hold up other requests.
+///
+/// At end of input or on shutdown, reading stops and in-flight requests get up to
+/// [`SHUTDOWN_TIMEOUT`] to finish and have their responses written.
+async fn serve_lines(
+    state: Arc<ContextServerState>,
+    reader: impl AsyncBufRead + Unpin,
+    mut writer: impl AsyncWrite + Unpin,
+    mut shutdown: Shutdown,
+) -> Result<()> {
+    let session = Arc::new(Session::default());
+    let _closing = ClosingSession {
+        state: state.clone(),
+        session: session.clone(),
+    };
+    let mut updates = state.updates.subscribe();
+    let (sender, mut responses) = mpsc::unbounded_channel::<Value>();
+    let mut sender = Some(sender);
+    let mut deadline = None;
+    let mut lines = reader.lines();
+
+    loop {
+        tokio::select! {
+            _ = shutdown.requested(), if sender.is_some() => {
+                sender = None;
+                deadline = Some(Instant::now() + SHUTDOWN_TIMEOUT);
+            }
+            _ = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                bail!("Timed out waiting for in-flight requests to finish");
+            }
+            line = lines.next_line(), if sender.is_some() => {
+                let Some(line) = line? else {
+                    sender = None;
+                    deadline = Some(Instant::now() + SHUTDOWN_TIMEOUT);
+                    continue;
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let (state, session, sender) = (state.clone(), session.clone(), sender.clone());
+                tokio::spawn(async move {
+                    if let (Some(response), Some(sender)) =
+                        (session.handle_message(&state, &line).await, sender)
+                    {
+                        let _ = sender.send(response);
+                    }
+                });
+            }
+            Ok(uri) = updates.recv(), if sender.is_some() => {
+                if let Some(notification) = session.notification_for(&uri) {
+                    write_message(&mut writer, &notification).await?;
+                }
+            }
+            response = responses.recv() => {
+                let Some(response) = response else {
+                    break;
+                };
+                write_message(&mut writer, &response).await?;
+            }
         }
     }
 
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Closes a session however [`serve_lines`] ends, including on read or write errors.
+struct ClosingSession {
+    state: Arc<ContextServerState>,
+    session: Arc<Session>,
+}
+
+impl Drop for ClosingSession {
+    fn drop(&mut self) {
+        self.session.close(&self.state);
+    }
+}
+
+async fn write_message(writer: &mut (impl AsyncWrite + Unpin), message: &Value) -> Result<()> {
+    let message_json = serde_json::to_string(message)?;
+    writer.write_all(message_json.as_bytes()).await?;
+    writer.write_all(b"\n").await?;
+    writer.flush().await?;
+
     Ok(())
 }
 
-fn get_current_time_info() -> String {
-    let local_now = chrono::Local::now();
-    let week = local_now.iso_week().week();
-    let day = local_now.format("%A").to_string();
+#[derive(Serialize)]
+struct TimeInfo {
+    timestamp: String,
+    unix_seconds: i64,
+    unix_millis: i64,
+    utc_offset: String,
+    offset_seconds: i32,
+    timezone: String,
+    abbreviation: String,
+    dst: bool,
+    iso_year: i32,
+    iso_week: u32,
+    weekday: u32,
+    weekday_name: String,
+    month_name: String,
+    day_of_year: u32,
+    quarter: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_hours: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    holiday: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    local_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    formatted: Option<String>,
+}
+
+impl TimeInfo {
+    fn current(
+        timezone: Option<Tz>,
+        format: Option<&TimeFormat>,
+        locale: Option<Locale>,
+    ) -> Result<Self> {
+        let timezone = timezone.unwrap_or_else(default_timezone);
+        let local_now = now(timezone).trunc_subsecs(3);
+        let offset = local_now.offset();
+        let utc_offset = local_now.format("%:z").to_string();
+        let abbreviation = offset.abbreviation().unwrap_or(&utc_offset).to_string();
+        let iso_week = local_now.iso_week();
+        let settings = settings();
+
+        Ok(Self {
+            timestamp: local_now.to_rfc3339_opts(SecondsFormat::Millis, false),
+            unix_seconds: local_now.timestamp(),
+            unix_millis: local_now.timestamp_millis(),
+            offset_seconds: offset.fix().local_minus_utc(),
+            utc_offset,
+            timezone: timezone.name().to_string(),
+            abbreviation,
+            dst: !offset.dst_offset().is_zero(),
+            iso_year: iso_week.year(),
+            iso_week: iso_week.week(),
+            weekday: local_now.weekday().number_from_monday(),
+            weekday_name: render(&local_now, "%A", locale)?,
+            month_name: render(&local_now, "%B", locale)?,
+            day_of_year: local_now.ordinal(),
+            quarter: local_now.month0() / 3 + 1,
+            working_hours: settings
+                .working_hours
+                .as_ref()
+                .map(|hours| hours.contains(local_now.naive_local(), &settings.holidays)),
+            holiday: settings
+                .holidays
+                .iter()
+                .find(|holiday| holiday.date == local_now.date_naive())
+                .map(|holiday| holiday.name.clone()),
+            locale: locale.map(|locale| locale.to_string()),
+            local_date: locale
+                .map(|locale| render(&local_now, "%x", Some(locale)))
+                .transpose()?,
+            formatted: format
+                .map(|format| format.format(&local_now, locale))
+                .transpose()?,
+        })
+    }
+
+    fn to_text(&self, verbosity: Verbosity) -> String {
+        if verbosity == Verbosity::Brief {
+            let mut text = format!(
+                "Current local time: {} ({}, UTC{})\n",
+                self.timestamp, self.timezone, self.utc_offset
+            );
+            if let Some(formatted) = &self.formatted {
+                text.push_str(&format!("Formatted: {}\n", formatted));
+            }
+            return text;
+        }
+
+        let mut text = formatdoc! {"
+            Current local time: {}
+            Time zone: {}
+            UTC offset: {}
+            Abbreviation: {}
+            Daylight saving time: {}
+            Week of the year: {}
+            Day of the week: {}
+        ",
+            self.timestamp,
+            self.timezone,
+            self.utc_offset,
+            self.abbreviation,
+            if self.dst { "yes" } else { "no" },
+            self.iso_week,
+            self.weekday_name,
+        };
+        if let Some(working_hours) = self.working_hours {
+            text.push_str(&format!(
+                "Within working hours: {}\n",
+                if working_hours { "yes" } else { "no" }
+            ));
+        }
+        if let Some(holiday) = &self.holiday {
+            text.push_str(&format!("Holiday: {}\n", holiday));
+        }
+        if let Some(local_date) = &self.local_date {
+            text.push_str(&format!("Date: {}\n", local_date));
+        }
+        if let Some(formatted) = &self.formatted {
+            text.push_str(&format!("Formatted: {}\n", formatted));
+        }
+        if verbosity == Verbosity::Full {
+            text.push_str(&formatdoc! {"
+                ISO week date: {}-W{:02}-{}
+                Month: {}
+                Quarter: Q{}
+                Day of the year: {}
+                Unix time: {}
+            ",
+                self.iso_year, self.iso_week, self.weekday,
+                self.month_name,
+                self.quarter,
+                self.day_of_year,
+                self.unix_seconds,
+            });
+        }
+
+        text
+    }
+}
+
+/// How much of [`TimeInfo`] its text rendering includes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Verbosity {
+    /// Just the time, zone and offset.
+    Brief,
+    #[default]
+    Standard,
+    /// Also the ISO week date, month, quarter, day of the year and Unix time.
+    Full,
+}
+
+/// JSON Schema of the structured payload returned by the `now` tool.
+fn time_info_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "timestamp": { "type": "string", "description": "ISO 8601 timestamp with millisecond precision and UTC offset." },
+            "unix_seconds": { "type": "integer" },
+            "unix_millis": { "type": "integer" },
+            "utc_offset": { "type": "string", "description": "UTC offset as +HH:MM." },
+            "offset_seconds": { "type": "integer" },
+            "timezone": { "type": "string", "description": "IANA time zone name." },
+            "abbreviation": { "type": "string" },
+            "dst": { "type": "boolean" },
+            "iso_year": { "type": "integer", "description": "ISO 8601 week-numbering year." },
+            "iso_week": { "type": "integer", "minimum": 1, "maximum": 53 },
+            "weekday": { "type": "integer", "minimum": 1, "maximum": 7, "description": "ISO 8601 weekday, Monday = 1." },
+            "weekday_name": { "type": "string", "description": "Weekday name, in the requested locale." },
+            "month_name": { "type": "string", "description": "Month name, in the requested locale." },
+            "day_of_year": { "type": "integer", "minimum": 1, "maximum": 366 },
+            "quarter": { "type": "integer", "minimum": 1, "maximum": 4 },
+            "working_hours": { "type": "boolean", "description": "Whether now is within the configured working hours; present only when they are configured." },
+            "holiday": { "type": "string", "description": "Name of today's holiday from the config file, if any." },
+            "locale": { "type": "string", "description": "Locale used for names; present only when locale is given." },
+            "local_date": { "type": "string", "description": "The date in the locale's own ordering; present only when locale is given." },
+            "formatted": { "type": "string", "description": "The time rendered with the requested format; present only when format is given." },
+        },
+        "required": [
+            "timestamp", "unix_seconds", "unix_millis", "utc_offset", "offset_seconds", "timezone",
+            "abbreviation", "dst", "iso_year", "iso_week", "weekday", "weekday_name", "month_name", "day_of_year",
+            "quarter",
+        ],
+    })
+}
+
+/// Parses the `format` and `locale` arguments, falling back to the configured defaults.
+fn format_and_locale(
+    format: Option<String>,
+    locale: Option<String>,
+) -> Result<(Option<TimeFormat>, Option<Locale>)> {
+    let defaults = settings();
+    let format = match format.as_deref() {
+        Some(format) => Some(TimeFormat::parse(format)?),
+        None => defaults.format,
+    };
+    let locale = match locale.as_deref() {
+        Some(locale) => Some(parse_locale(locale)?),
+        None => defaults.locale,
+    };
 
-    formatdoc! {"
-        Current local time: {}
-        Week of the year: {}
-        Day of the week: {}
-    ", local_now, week, day}
+    Ok((format, locale))
+}
+
+const FORMAT_DESCRIPTION: &str = "Extra rendering of the time: a strftime pattern such as \"%A, %-d %B\", or one of the presets rfc2822, rfc3339, iso8601, date, time, long or unix.";
+const LOCALE_DESCRIPTION: &str = "Locale for weekday and month names and the local date ordering, e.g. \"de-DE\", \"ja-JP\" or \"fr-FR\". Defaults to the server's default locale, else English names.";
+
+#[derive(Deserialize)]
+struct NowArguments {
+    timezone: Option<String>,
+    format: Option<String>,
+    locale: Option<String>,
 }
 
 struct NowTool;
 
 #[async_trait]
 impl ToolExecutor for NowTool {
-    async fn execute(&self, _arguments: Option<Value>) -> Result<Vec<ToolContent>> {
-        let result = get_current_time_info();
-        Ok(vec![ToolContent::Text { text: result }])
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: NowArguments = parse_arguments(arguments)?;
+        let timezone = arguments
+            .timezone
+            .as_deref()
+            .map(parse_timezone)
+            .transpose()?;
+        let (format, locale) = format_and_locale(arguments.format, arguments.locale)?;
+        let info = TimeInfo::current(timezone, format.as_ref(), locale)?;
+
+        Ok(vec![
+            ToolContent::Text {
+                text: info.to_text(Verbosity::Standard),
+            },
+            ToolContent::Text {
+                text: serde_json::to_string(&info)?,
+            },
+        ])
     }
 
     fn to_tool(&self) -> Tool {
         Tool {
             name: "now".into(),
             description: Some(
-                "Retrieve the current local time, week of the year, and day of the week.".into(),
+                "Retrieve the current local time, UTC offset, time zone abbreviation, daylight saving status, week of the year, and day of the week. Defaults to the server's default time zone. Returns a readable summary, plus the same information as structured content, which is also serialized as JSON in a second text item.".into(),
             ),
             input_schema: json!({
                 "type": "object",
-                "properties": {},
+                "properties": {
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA time zone name, team zone or city, e.g. \"Asia/Tokyo\", \"Europe/London\" or \"Lagos\".",
+                    },
+                    "format": {
+                        "type": "string",
+                        "description": FORMAT_DESCRIPTION,
+                    },
+                    "locale": {
+                        "type": "string",
+                        "description": LOCALE_DESCRIPTION,
+                    },
+                },
             }),
         }
     }
 }
 
+#[derive(Deserialize)]
+struct NowPromptArguments {
+    timezone: Option<String>,
+    format: Option<String>,
+    locale: Option<String>,
+    #[serde(default)]
+    verbosity: Verbosity,
+}
+
 struct NowPrompt;
 
 #[async_trait]
@@ -121,8 +754,16 @@ impl PromptExecutor for NowPrompt {
         "Now"
     }
 
-    async fn compute(&self, _arguments: Option<Value>) -> Result<ComputedPrompt> {
-        let content = get_current_time_info();
+    async fn compute(&self, arguments: Option<Value>) -> Result<ComputedPrompt> {
+        let arguments: NowPromptArguments = parse_arguments(arguments)?;
+        let timezone = arguments
+            .timezone
+            .as_deref()
+            .map(parse_timezone)
+            .transpose()?;
+        let (format, locale) = format_and_locale(arguments.format, arguments.locale)?;
+        let content =
+            TimeInfo::current(timezone, format.as_ref(), locale)?.to_text(arguments.verbosity);
 
         Ok(ComputedPrompt {
             description: "Current time information".into(),
@@ -138,7 +779,32 @@ impl PromptExecutor for NowPrompt {
     fn to_prompt(&self) -> Prompt {
         Prompt {
             name: self.name().to_string(),
-            arguments: vec![],
+            arguments: vec![
+                PromptArgument {
+                    name: "timezone".into(),
+                    description: Some(
+                        "IANA time zone name, team zone or city, e.g. \"Asia/Tokyo\" or \"Lagos\". Defaults to the server's default time zone.".into(),
+                    ),
+                    required: Some(false),
+                },
+                PromptArgument {
+                    name: "format".into(),
+                    description: Some(FORMAT_DESCRIPTION.into()),
+                    required: Some(false),
+                },
+                PromptArgument {
+                    name: "locale".into(),
+                    description: Some(LOCALE_DESCRIPTION.into()),
+                    required: Some(false),
+                },
+                PromptArgument {
+                    name: "verbosity".into(),
+                    description: Some(
+                        "\"brief\" for just the time and zone, \"standard\", or \"full\" to add the ISO week date, month, quarter, day of the year and Unix time. Defaults to \"standard\".".into(),
+                    ),
+                    required: Some(false),
+                },
+            ],
         }
     }
 }
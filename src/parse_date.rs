@@ -0,0 +1,909 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::{
+    DateTime, Datelike, Days, Duration, MappedLocalTime, Months, NaiveDate, NaiveDateTime,
+    NaiveTime, SubsecRound, TimeZone, Weekday,
+};
+use chrono_tz::Tz;
+use context_server::{Tool, ToolContent, ToolExecutor};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    arguments::parse_arguments,
+    date_add::{add_months, out_of_range},
+    error::invalid_arguments,
+    timezone::{
+        default_timezone, now, parse_datetime, parse_naive_datetime, parse_timezone, resolve_local,
+        Disambiguation,
+    },
+};
+
+/// What an expression resolves to.
+#[derive(Debug, Clone, Copy)]
+pub enum Resolution {
+    Instant(DateTime<Tz>),
+    Day(NaiveDate),
+    /// A half-open range of whole days, `[start, end)`.
+    Period(NaiveDate, NaiveDate),
+}
+
+#[derive(Debug)]
+pub struct ParsedDate {
+    pub resolution: Resolution,
+    pub explanation: String,
+    /// Another plausible reading, present when the expression is ambiguous.
+    pub alternative: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+#[derive(Clone, Copy)]
+enum Period {
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// Resolves a free-text English date expression relative to `reference`.
+pub fn parse_expression(input: &str, reference: DateTime<Tz>) -> Result<ParsedDate> {
+    let trimmed = input.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Ok(ParsedDate {
+            resolution: Resolution::Day(date),
+            explanation: "read as an ISO 8601 date".into(),
+            alternative: None,
+        });
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(ParsedDate {
+            resolution: Resolution::Instant(datetime.with_timezone(&reference.timezone())),
+            explanation: "read as an RFC 3339 timestamp".into(),
+            alternative: None,
+        });
+    }
+    if let Some(local) = parse_naive_datetime(trimmed) {
+        let (datetime, note) = resolve(reference.timezone(), local);
+        return Ok(ParsedDate {
+            resolution: Resolution::Instant(datetime),
+            explanation: "read as an ISO 8601 local date-time".into(),
+            alternative: note,
+        });
+    }
+
+    let tokens = tokenize(input);
+    if tokens.is_empty() {
+        bail!(invalid_arguments!("Invalid arguments: expression is empty"));
+    }
+
+    let (date_tokens, time) = split_time(&tokens);
+    let mut parsed =
+        parse_date_tokens(date_tokens, reference, time.is_some()).ok_or_else(|| {
+            invalid_arguments!("Could not understand the date expression: {}", input)
+        })??;
+
+    if let Some((time, alternative_time)) = time {
+        let date = match parsed.resolution {
+            Resolution::Instant(datetime) => datetime.date_naive(),
+            Resolution::Day(date) => date,
+            Resolution::Period(..) => {
+                bail!(invalid_arguments!(
+                    "A time of day cannot be combined with a whole period: {}",
+                    input
+                ))
+            }
+        };
+        let (datetime, note) = resolve(reference.timezone(), date.and_time(time));
+        parsed.resolution = Resolution::Instant(datetime);
+        parsed.explanation = format!("{} at {}", parsed.explanation, time.format("%H:%M"));
+        if let Some(alternative_time) = alternative_time {
+            parsed.alternative.get_or_insert(format!(
+                "the hour has no am/pm, so it could also mean {} at {}",
+                date,
+                alternative_time.format("%H:%M")
+            ));
+        }
+        if let Some(note) = note {
+            parsed.alternative.get_or_insert(note);
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn tokenize(input: &str) -> Vec<String> {
+    input
+        .to_lowercase()
+        .replace([',', '.'], "")
+        .split_whitespace()
+        .filter(|token| !matches!(*token, "the" | "on"))
+        .map(str::to_string)
+        .collect()
+}
+
+type TimeOfDay = (NaiveTime, Option<NaiveTime>);
+
+/// Splits a trailing time of day ("at 3pm", "15:30", "noon") off the tokens.
+fn split_time(tokens: &[String]) -> (&[String], Option<TimeOfDay>) {
+    let strip_at = |rest: &'_ [String]| -> usize {
+        if rest.last().is_some_and(|token| token == "at") {
+            rest.len() - 1
+        } else {
+            rest.len()
+        }
+    };
+
+    if let [rest @ .., hour, meridiem] = tokens {
+        if matches!(meridiem.as_str(), "am" | "pm") {
+            if let Some(time) = parse_time(hour, Some(meridiem), true) {
+                return (&rest[..strip_at(rest)], Some(time));
+            }
+        }
+    }
+
+    if let [rest @ .., token] = tokens {
+        let after_at = rest.last().is_some_and(|token| token == "at");
+        if let Some(time) = parse_time(token, None, after_at) {
+            return (&rest[..strip_at(rest)], Some(time));
+        }
+    }
+
+    (tokens, None)
+}
+
+/// Parses a time of day. Bare hours are only accepted when `allow_bare` (after "at").
+fn parse_time(token: &str, meridiem: Option<&str>, allow_bare: bool) -> Option<TimeOfDay> {
+    match token {
+        "noon" | "midday" => return Some((NaiveTime::from_hms_opt(12, 0, 0)?, None)),
+        "midnight" => return Some((NaiveTime::MIN, None)),
+        _ => {}
+    }
+
+    let (token, meridiem) = match meridiem {
+        Some(meridiem) => (token, Some(meridiem)),
+        None => match token
+            .strip_suffix("am")
+            .or_else(|| token.strip_suffix("pm"))
+        {
+            Some(stripped) => (stripped, Some(&token[stripped.len()..])),
+            None => (token, None),
+        },
+    };
+
+    let (hour, minute) = match token.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        None if meridiem.is_some() || allow_bare => (token.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+
+    match meridiem {
+        Some(meridiem) => {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            let hour = hour % 12 + if meridiem == "pm" { 12 } else { 0 };
+            Some((NaiveTime::from_hms_opt(hour, minute, 0)?, None))
+        }
+        None => {
+            let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
+            let alternative = (!token.contains(':') && (1..12).contains(&hour))
+                .then(|| NaiveTime::from_hms_opt(hour + 12, minute, 0))
+                .flatten();
+            Some((time, alternative))
+        }
+    }
+}
+
+/// `None` when the tokens are not a known expression, an error when one resolves out of range.
+fn parse_date_tokens(
+    tokens: &[String],
+    reference: DateTime<Tz>,
+    has_time: bool,
+) -> Option<Result<ParsedDate>> {
+    let today = reference.date_naive();
+    let words = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let day = |date: NaiveDate, explanation: &str| ParsedDate {
+        resolution: Resolution::Day(date),
+        explanation: explanation.to_string(),
+        alternative: None,
+    };
+
+    let parsed = match words.as_slice() {
+        [] if has_time => day(today, "today"),
+        ["now"] | ["right", "now"] => ParsedDate {
+            resolution: Resolution::Instant(reference),
+            explanation: "the reference instant".into(),
+            alternative: None,
+        },
+        ["today"] | ["tonight"] => day(today, "the reference date"),
+        ["tomorrow"] => day(today.succ_opt()?, "the day after the reference date"),
+        ["yesterday"] => day(today.pred_opt()?, "the day before the reference date"),
+        ["day", "after", "tomorrow"] => day(
+            today.checked_add_days(Days::new(2))?,
+            "two days after the reference date",
+        ),
+        ["day", "before", "yesterday"] => day(
+            today.checked_sub_days(Days::new(2))?,
+            "two days before the reference date",
+        ),
+        ["in", amount, unit] | [amount, unit, "from", "now"] | [amount, unit, "later"] => {
+            return Some(relative(
+                reference,
+                parse_amount(amount)?,
+                parse_unit(unit)?,
+            ));
+        }
+        [amount, unit, "ago"] => {
+            let (amount, unit) = (parse_amount(amount)?, parse_unit(unit)?);
+            return Some(
+                amount
+                    .checked_neg()
+                    .ok_or_else(out_of_range)
+                    .and_then(|amount| relative(reference, amount, unit)),
+            );
+        }
+        [weekday] => upcoming_weekday(today, parse_weekday(weekday)?, "")?,
+        ["this", weekday] if parse_weekday(weekday).is_some() => {
+            upcoming_weekday(today, parse_weekday(weekday)?, "this ")?
+        }
+        ["next", weekday] if parse_weekday(weekday).is_some() => {
+            next_weekday(today, parse_weekday(weekday)?)?
+        }
+        ["last", weekday] if parse_weekday(weekday).is_some() => {
+            last_weekday(today, parse_weekday(weekday)?)?
+        }
+        [modifier @ ("this" | "next" | "last"), period] => {
+            let period = parse_period(period)?;
+            let (start, end) = period_bounds(today, period, shift(modifier))?;
+            ParsedDate {
+                resolution: Resolution::Period(start, end),
+                explanation: format!("{} {}", modifier, period.describe()),
+                alternative: None,
+            }
+        }
+        [edge @ ("start" | "beginning" | "end"), "of", rest @ ..] => {
+            let (period, offset, label) = match rest {
+                [period] => (parse_period(period)?, 0, "this"),
+                [modifier @ ("this" | "next" | "last"), period] => {
+                    (parse_period(period)?, shift(modifier), *modifier)
+                }
+                _ => return None,
+            };
+            let (start, end) = period_bounds(today, period, offset)?;
+            if *edge == "end" {
+                let last = end.pred_opt()?;
+                ParsedDate {
+                    resolution: Resolution::Day(last),
+                    explanation: format!("the last day of {} {}", label, period.describe()),
+                    alternative: matches!(period, Period::Week).then(|| {
+                        format!(
+                            "it could also mean the end of the work week, {}",
+                            last - Days::new(2)
+                        )
+                    }),
+                }
+            } else {
+                day(
+                    start,
+                    &format!("the first day of {} {}", label, period.describe()),
+                )
+            }
+        }
+        _ => return None,
+    };
+
+    Some(Ok(parsed))
+}
+
+fn relative(reference: DateTime<Tz>, amount: i64, unit: Unit) -> Result<ParsedDate> {
+    let timezone = reference.timezone();
+    let local = reference.naive_local();
+    let elapsed = |duration: Option<Duration>| {
+        duration
+            .and_then(|duration| reference.checked_add_signed(duration))
+            .ok_or_else(out_of_range)
+    };
+
+    let (datetime, note) = match unit {
+        Unit::Second => (elapsed(Duration::try_seconds(amount))?, None),
+        Unit::Minute => (elapsed(Duration::try_minutes(amount))?, None),
+        Unit::Hour => (elapsed(Duration::try_hours(amount))?, None),
+        Unit::Day | Unit::Week => {
+            let days = match unit {
+                Unit::Week => amount.checked_mul(7),
+                _ => Some(amount),
+            };
+            let shifted = days
+                .and_then(Duration::try_days)
+                .and_then(|days| local.checked_add_signed(days))
+                .ok_or_else(out_of_range)?;
+            resolve(timezone, shifted)
+        }
+        Unit::Month | Unit::Quarter | Unit::Year => {
+            let months = match unit {
+                Unit::Quarter => amount.checked_mul(3),
+                Unit::Year => amount.checked_mul(12),
+                _ => Some(amount),
+            }
+            .ok_or_else(out_of_range)?;
+            resolve(timezone, add_months(local, months)?)
+        }
+    };
+
+    Ok(ParsedDate {
+        resolution: Resolution::Instant(datetime),
+        explanation: format!(
+            "{} {} {} the reference instant",
+            amount.unsigned_abs(),
+            unit.describe(amount.unsigned_abs()),
+            if amount < 0 { "before" } else { "after" }
+        ),
+        alternative: note,
+    })
+}
+
+fn upcoming_weekday(today: NaiveDate, weekday: Weekday, prefix: &str) -> Option<ParsedDate> {
+    let ahead = days_until(today.weekday(), weekday);
+    let date = today.checked_add_days(Days::new(ahead))?;
+    let this_week =
+        week_start(today)?.checked_add_days(Days::new(weekday.num_days_from_monday() as u64))?;
+
+    Some(ParsedDate {
+        resolution: Resolution::Day(date),
+        explanation: format!(
+            "\"{}{}\" as the next {} on or after the reference date",
+            prefix,
+            weekday_name(weekday),
+            weekday_name(weekday)
+        ),
+        alternative: (this_week != date).then(|| {
+            format!(
+                "it could also mean the {} of the current week, which has already passed: {}",
+                weekday_name(weekday),
+                this_week
+            )
+        }),
+    })
+}
+
+fn next_weekday(today: NaiveDate, weekday: Weekday) -> Option<ParsedDate> {
+    let ahead = match days_until(today.weekday(), weekday) {
+        0 => 7,
+        ahead => ahead,
+    };
+    let date = today.checked_add_days(Days::new(ahead))?;
+    let next_week = week_start(today)?
+        .checked_add_days(Days::new(7 + weekday.num_days_from_monday() as u64))?;
+
+    Some(ParsedDate {
+        resolution: Resolution::Day(date),
+        explanation: format!(
+            "\"next {}\" as the first {} after the reference date",
+            weekday_name(weekday),
+            weekday_name(weekday)
+        ),
+        alternative: (next_week != date).then(|| {
+            format!(
+                "it could also mean the {} of next week: {}",
+                weekday_name(weekday),
+                next_week
+            )
+        }),
+    })
+}
+
+fn last_weekday(today: NaiveDate, weekday: Weekday) -> Option<ParsedDate> {
+    let behind = match days_until(weekday, today.weekday()) {
+        0 => 7,
+        behind => behind,
+    };
+    let date = today.checked_sub_days(Days::new(behind))?;
+    let last_week = week_start(today)?.checked_sub_days(Days::new(7))?
+        + Days::new(weekday.num_days_from_monday() as u64);
+
+    Some(ParsedDate {
+        resolution: Resolution::Day(date),
+        explanation: format!(
+            "\"last {}\" as the most recent {} before the reference date",
+            weekday_name(weekday),
+            weekday_name(weekday)
+        ),
+        alternative: (last_week != date).then(|| {
+            format!(
+                "it could also mean the {} of last week: {}",
+                weekday_name(weekday),
+                last_week
+            )
+        }),
+    })
+}
+
+/// Days from `from` forward to the next `to`, 0 when they are the same weekday.
+fn days_until(from: Weekday, to: Weekday) -> u64 {
+    ((7 + to.num_days_from_monday() - from.num_days_from_monday()) % 7) as u64
+}
+
+fn week_start(date: NaiveDate) -> Option<NaiveDate> {
+    date.checked_sub_days(Days::new(date.weekday().num_days_from_monday() as u64))
+}
+
+/// Bounds `[start, end)` of the period containing `today`, shifted by `offset` periods.
+fn period_bounds(today: NaiveDate, period: Period, offset: i64) -> Option<(NaiveDate, NaiveDate)> {
+    match period {
+        Period::Week => {
+            let start = week_start(today)?.checked_add_signed(Duration::weeks(offset))?;
+            Some((start, start.checked_add_days(Days::new(7))?))
+        }
+        Period::Month | Period::Quarter | Period::Year => {
+            let months = match period {
+                Period::Month => 1,
+                Period::Quarter => 3,
+                _ => 12,
+            };
+            let first_month = (today.month0() / months) * months + 1;
+            let start = add_months(
+                NaiveDate::from_ymd_opt(today.year(), first_month, 1)?.into(),
+                offset * months as i64,
+            )
+            .ok()?
+            .date();
+            let end = start.checked_add_months(Months::new(months))?;
+            Some((start, end))
+        }
+    }
+}
+
+/// Resolves a local time like RFC 5545 does: the first of two repeated instants, or
+/// shifted forward past a skipped hour. Either case is reported.
+fn resolve(timezone: Tz, local: NaiveDateTime) -> (DateTime<Tz>, Option<String>) {
+    match timezone.from_local_datetime(&local) {
+        MappedLocalTime::Single(datetime) => (datetime, None),
+        MappedLocalTime::Ambiguous(earlier, later) => (
+            earlier,
+            Some(format!(
+                "{} occurs twice in {}; the second occurrence is {}",
+                local,
+                timezone.name(),
+                later.to_rfc3339()
+            )),
+        ),
+        MappedLocalTime::None => {
+            let datetime = resolve_local(timezone, local, Disambiguation::Later)
+                .unwrap_or_else(|_| timezone.from_utc_datetime(&local));
+            (
+                datetime,
+                Some(format!(
+                    "{} is skipped by a daylight saving transition in {}, so it was shifted forward",
+                    local,
+                    timezone.name()
+                )),
+            )
+        }
+    }
+}
+
+fn shift(modifier: &str) -> i64 {
+    match modifier {
+        "next" => 1,
+        "last" => -1,
+        _ => 0,
+    }
+}
+
+fn parse_amount(word: &str) -> Option<i64> {
+    let amount = match word {
+        "a" | "an" | "one" => 1,
+        "two" | "couple" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        _ => word.parse().ok()?,
+    };
+    Some(amount)
+}
+
+fn parse_unit(word: &str) -> Option<Unit> {
+    let unit = match word.strip_suffix('s').unwrap_or(word) {
+        "second" | "sec" => Unit::Second,
+        "minute" | "min" => Unit::Minute,
+        "hour" | "hr" => Unit::Hour,
+        "day" => Unit::Day,
+        "week" => Unit::Week,
+        "month" => Unit::Month,
+        "quarter" => Unit::Quarter,
+        "year" => Unit::Year,
+        _ => return None,
+    };
+    Some(unit)
+}
+
+fn parse_period(word: &str) -> Option<Period> {
+    match word {
+        "week" => Some(Period::Week),
+        "month" => Some(Period::Month),
+        "quarter" => Some(Period::Quarter),
+        "year" => Some(Period::Year),
+        _ => None,
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+impl Unit {
+    fn describe(self, amount: u64) -> String {
+        let name = match self {
+            Unit::Second => "second",
+            Unit::Minute => "minute",
+            Unit::Hour => "hour",
+            Unit::Day => "day",
+            Unit::Week => "week",
+            Unit::Month => "month",
+            Unit::Quarter => "quarter",
+            Unit::Year => "year",
+        };
+        if amount == 1 {
+            name.to_string()
+        } else {
+            format!("{}s", name)
+        }
+    }
+}
+
+impl Period {
+    fn describe(self) -> &'static str {
+        match self {
+            Period::Week => "week (Monday to Sunday)",
+            Period::Month => "month",
+            Period::Quarter => "quarter",
+            Period::Year => "year",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ParseDateArguments {
+    expression: String,
+    reference: Option<String>,
+    timezone: Option<String>,
+}
+
+pub struct ParseDateTool;
+
+#[async_trait]
+impl ToolExecutor for ParseDateTool {
+    async fn execute(&self, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+        let arguments: ParseDateArguments = parse_arguments(arguments)?;
+        let timezone = match arguments.timezone.as_deref() {
+            Some(name) => parse_timezone(name)?,
+            None => default_timezone(),
+        };
+        let reference = match arguments.reference.as_deref() {
+            Some(reference) => parse_datetime(reference, timezone, Disambiguation::Reject)?,
+            None => now(timezone).trunc_subsecs(0),
+        };
+
+        let parsed = parse_expression(&arguments.expression, reference)?;
+
+        let mut lines = vec![
+            format!("Expression: {}", arguments.expression),
+            format!(
+                "Reference: {} ({})",
+                reference.to_rfc3339(),
+                reference.format("%A")
+            ),
+        ];
+        match parsed.resolution {
+            Resolution::Instant(datetime) => {
+                lines.push(format!("Resolved: {}", datetime.to_rfc3339()));
+            }
+            Resolution::Day(date) => {
+                lines.push(format!("Resolved: {} ({})", date, date.format("%A")));
+                lines.push(format!(
+                    "Interval: {}",
+                    interval(timezone, date, date.succ_opt().unwrap_or(date))
+                ));
+            }
+            Resolution::Period(start, end) => {
+                lines.push(format!(
+                    "Resolved: {}/{}",
+                    start,
+                    end.pred_opt().unwrap_or(end)
+                ));
+                lines.push(format!("Interval: {}", interval(timezone, start, end)));
+            }
+        }
+        lines.push(format!("Interpretation: {}", parsed.explanation));
+        match parsed.alternative {
+            Some(alternative) => lines.push(format!("Ambiguous: yes; {}", alternative)),
+            None => lines.push("Ambiguous: no".to_string()),
+        }
+
+        Ok(vec![ToolContent::Text {
+            text: lines.join("\n"),
+        }])
+    }
+
+    fn to_tool(&self) -> Tool {
+        Tool {
+            name: "parse_date".into(),
+            description: Some(
+                "Resolve a free-text English date expression to an ISO 8601 date, date-time or interval, relative to a reference instant (defaults to now, from the same clock as the now tool). Understands phrases like \"tomorrow at 3pm\", \"next Tuesday\", \"in 3 weeks\", \"2 days ago\", \"next month\" and \"end of quarter\". Reports how the expression was interpreted and flags ambiguous readings with the alternative.".into(),
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "expression": {
+                        "type": "string",
+                        "description": "The expression to resolve, e.g. \"next Tuesday\".",
+                    },
+                    "reference": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp or local date-time to resolve against. Defaults to now.",
+                    },
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA time zone for the reference and result. Defaults to the server's default time zone.",
+                    },
+                },
+                "required": ["expression"],
+            }),
+        }
+    }
+}
+
+fn interval(timezone: Tz, start: NaiveDate, end: NaiveDate) -> String {
+    let (start, _) = resolve(timezone, start.into());
+    let (end, _) = resolve(timezone, end.into());
+    format!("{}/{}", start.to_rfc3339(), end.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wednesday, 14 October 2026, at noon in New York.
+    const WEDNESDAY: &str = "2026-10-14T12:00:00-04:00";
+
+    fn parse(expression: &str, reference: &str) -> ParsedDate {
+        let reference = DateTime::parse_from_rfc3339(reference)
+            .unwrap()
+            .with_timezone(&Tz::America__New_York);
+        parse_expression(expression, reference).unwrap()
+    }
+
+    fn day(expression: &str) -> String {
+        match parse(expression, WEDNESDAY).resolution {
+            Resolution::Day(date) => date.to_string(),
+            resolution => panic!("{} resolved to {:?}", expression, resolution),
+        }
+    }
+
+    fn instant(expression: &str, reference: &str) -> String {
+        match parse(expression, reference).resolution {
+            Resolution::Instant(datetime) => datetime.to_rfc3339(),
+            resolution => panic!("{} resolved to {:?}", expression, resolution),
+        }
+    }
+
+    fn period(expression: &str) -> (String, String) {
+        match parse(expression, WEDNESDAY).resolution {
+            Resolution::Period(start, end) => (start.to_string(), end.to_string()),
+            resolution => panic!("{} resolved to {:?}", expression, resolution),
+        }
+    }
+
+    #[test]
+    fn days_relative_to_today() {
+        assert_eq!(day("today"), "2026-10-14");
+        assert_eq!(day("tomorrow"), "2026-10-15");
+        assert_eq!(day("yesterday"), "2026-10-13");
+        assert_eq!(day("the day after tomorrow"), "2026-10-16");
+        assert_eq!(day("day before yesterday"), "2026-10-12");
+        assert_eq!(day("2027-01-31"), "2027-01-31");
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(day("Friday"), "2026-10-16");
+        assert_eq!(day("wednesday"), "2026-10-14");
+        assert_eq!(day("this Mon"), "2026-10-19");
+        assert_eq!(day("next Friday"), "2026-10-16");
+        assert_eq!(day("next Wednesday"), "2026-10-21");
+        assert_eq!(day("last Monday"), "2026-10-12");
+        assert_eq!(day("last Wednesday"), "2026-10-07");
+        assert_eq!(day("last Friday"), "2026-10-09");
+    }
+
+    #[test]
+    fn ambiguous_weekdays_report_the_other_reading() {
+        let next = parse("next Friday", WEDNESDAY);
+        assert!(next.alternative.unwrap().contains("2026-10-23"));
+
+        let this = parse("Monday", WEDNESDAY);
+        assert!(this.alternative.unwrap().contains("2026-10-12"));
+
+        assert!(parse("next Wednesday", WEDNESDAY).alternative.is_none());
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(
+            period("this week"),
+            ("2026-10-12".into(), "2026-10-19".into())
+        );
+        assert_eq!(
+            period("next week"),
+            ("2026-10-19".into(), "2026-10-26".into())
+        );
+        assert_eq!(
+            period("last month"),
+            ("2026-09-01".into(), "2026-10-01".into())
+        );
+        assert_eq!(
+            period("next quarter"),
+            ("2027-01-01".into(), "2027-04-01".into())
+        );
+        assert_eq!(
+            period("this year"),
+            ("2026-01-01".into(), "2027-01-01".into())
+        );
+        assert_eq!(day("end of month"), "2026-10-31");
+        assert_eq!(day("start of next month"), "2026-11-01");
+        assert_eq!(day("end of last quarter"), "2026-09-30");
+        assert_eq!(day("beginning of the year"), "2026-01-01");
+    }
+
+    #[test]
+    fn relative_amounts() {
+        assert_eq!(
+            instant("in 2 hours", WEDNESDAY),
+            "2026-10-14T14:00:00-04:00"
+        );
+        assert_eq!(
+            instant("90 minutes ago", WEDNESDAY),
+            "2026-10-14T10:30:00-04:00"
+        );
+        assert_eq!(
+            instant("in three weeks", WEDNESDAY),
+            "2026-11-04T12:00:00-05:00"
+        );
+        assert_eq!(
+            instant("a month from now", WEDNESDAY),
+            "2026-11-14T12:00:00-05:00"
+        );
+        assert_eq!(
+            instant("2 quarters ago", WEDNESDAY),
+            "2026-04-14T12:00:00-04:00"
+        );
+        assert_eq!(instant("in 1 year", WEDNESDAY), "2027-10-14T12:00:00-04:00");
+        assert_eq!(
+            instant("in 1 month", "2027-01-31T09:00:00-05:00"),
+            "2027-02-28T09:00:00-05:00"
+        );
+    }
+
+    #[test]
+    fn times_of_day() {
+        assert_eq!(
+            instant("tomorrow at 3pm", WEDNESDAY),
+            "2026-10-15T15:00:00-04:00"
+        );
+        assert_eq!(
+            instant("friday 9:30", WEDNESDAY),
+            "2026-10-16T09:30:00-04:00"
+        );
+        assert_eq!(instant("noon", WEDNESDAY), "2026-10-14T12:00:00-04:00");
+
+        let bare = parse("tomorrow at 3", WEDNESDAY);
+        assert!(matches!(bare.resolution, Resolution::Instant(datetime)
+            if datetime.to_rfc3339() == "2026-10-15T03:00:00-04:00"));
+        assert!(bare.alternative.unwrap().contains("15:00"));
+    }
+
+    #[test]
+    fn repeated_local_time_takes_the_first_occurrence() {
+        let parsed = parse("in 1 day", "2026-10-31T01:30:00-04:00");
+        assert!(matches!(parsed.resolution, Resolution::Instant(datetime)
+            if datetime.to_rfc3339() == "2026-11-01T01:30:00-04:00"));
+        assert!(parsed
+            .alternative
+            .unwrap()
+            .contains("2026-11-01T01:30:00-05:00"));
+    }
+
+    #[test]
+    fn skipped_local_time_moves_forward() {
+        let parsed = parse("tomorrow at 2:30am", "2026-03-07T12:00:00-05:00");
+        assert!(matches!(parsed.resolution, Resolution::Instant(datetime)
+            if datetime.to_rfc3339() == "2026-03-08T03:30:00-04:00"));
+        assert!(parsed.alternative.unwrap().contains("skipped"));
+    }
+
+    #[test]
+    fn hours_count_elapsed_time_across_transitions() {
+        assert_eq!(
+            instant("in 2 hours", "2026-11-01T00:30:00-04:00"),
+            "2026-11-01T01:30:00-05:00"
+        );
+    }
+
+    #[test]
+    fn out_of_range_amounts_are_errors() {
+        let reference = DateTime::parse_from_rfc3339(WEDNESDAY)
+            .unwrap()
+            .with_timezone(&Tz::UTC);
+        for expression in [
+            "in 100000000 days",
+            "in 9223372036854775807 weeks",
+            "in 9223372036854775807 seconds",
+            "100000000 years ago",
+            "-9223372036854775808 days ago",
+            "in 4000000000 quarters",
+        ] {
+            let error = parse_expression(expression, reference).unwrap_err();
+            assert!(error.to_string().contains("out of range"), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn unknown_expressions_are_errors() {
+        let reference = DateTime::parse_from_rfc3339(WEDNESDAY)
+            .unwrap()
+            .with_timezone(&Tz::UTC);
+        for expression in [
+            "",
+            "someday",
+            "next fortnight",
+            "in many days",
+            "end of week 3",
+        ] {
+            assert!(
+                parse_expression(expression, reference).is_err(),
+                "{}",
+                expression
+            );
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use std::{collections::HashMap, sync::Arc};
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use async_trait::async_trait;
 use context_server::{ComputedPrompt, Prompt, PromptDelegate, PromptExecutor};
 use parking_lot::RwLock;
 use serde_json::Value;
 
+use crate::error::not_found;
+
 #[derive(Default)]
 pub struct PromptRegistry(RwLock<HashMap<String, Arc<dyn PromptExecutor>>>);
 
@@ -14,6 +16,10 @@ impl PromptRegistry {
         self.0.write().insert(prompt.name().to_string(), prompt);
     }
 
+    pub fn unregister(&self, name: &str) {
+        self.0.write().remove(name);
+    }
+
     pub fn list_prompts(&self) -> Vec<Prompt> {
         self.0.read().values().map(|p| p.to_prompt()).collect()
     }
@@ -27,7 +33,7 @@ impl PromptRegistry {
             .0
             .read()
             .get(prompt)
-            .ok_or_else(|| anyhow!("Prompt not found: {}", prompt))?
+            .ok_or_else(|| not_found!("Prompt not found: {}", prompt))?
             .clone();
 
         prompt.compute(arguments).await
@@ -1,10 +1,51 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use context_server::{Resource, ResourceContent, ResourceContentType, ResourceDelegate};
 
 use parking_lot::RwLock;
+use serde::Serialize;
+
+use crate::{error::not_found, uri_template::UriTemplate};
+
+/// A family of resources whose URIs match `uri_template`, as listed by
+/// `resources/templates/list`.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceTemplate {
+    pub uri_template: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+/// The body of a resource. Binary data is sent base64-encoded as a `blob`.
+pub enum ResourcePayload {
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// A resource whose content is computed on every read.
+#[async_trait]
+pub trait ResourceProvider: Send + Sync {
+    /// The listing entry; its `mime_type` describes the payload.
+    fn to_resource(&self) -> Resource;
+
+    async fn read(&self) -> Result<ResourcePayload>;
+}
+
+/// Resources whose URIs match a template, computed from the URI's variables on every read.
+#[async_trait]
+pub trait TemplateProvider: Send + Sync {
+    fn to_template(&self) -> ResourceTemplate;
+
+    /// Should fail with `invalid_arguments!` for variables it cannot satisfy.
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<ResourcePayload>;
+}
 
 #[derive(Default)]
 pub struct ResourceRegistry {
@@ -13,31 +54,114 @@ pub struct ResourceRegistry {
 
 #[derive(Default)]
 struct Inner {
-    resources: HashMap<String, Resource>,
-    contents: HashMap<String, String>,
+    resources: HashMap<String, Arc<dyn ResourceProvider>>,
+    templates: Vec<Template>,
+    /// Number of subscribers per URI, across all clients.
+    subscriptions: HashMap<String, usize>,
+}
+
+struct Template {
+    provider: Arc<dyn TemplateProvider>,
+    pattern: UriTemplate,
 }
 
 impl ResourceRegistry {
-    #[allow(unused)]
-    pub fn register(&self, resource: Resource, content: String) {
-        let mut guard = self.inner.write();
-        guard.contents.insert(resource.uri.clone(), content);
-        guard.resources.insert(resource.uri.clone(), resource);
+    pub fn register(&self, provider: Arc<dyn ResourceProvider>) {
+        let uri = provider.to_resource().uri;
+        self.inner.write().resources.insert(uri, provider);
+    }
+
+    pub fn register_template(&self, provider: Arc<dyn TemplateProvider>) -> Result<()> {
+        let pattern = UriTemplate::parse(&provider.to_template().uri_template)?;
+        self.inner
+            .write()
+            .templates
+            .push(Template { provider, pattern });
+
+        Ok(())
+    }
+
+    pub fn unregister(&self, uri: &str) {
+        self.inner.write().resources.remove(uri);
     }
 
     pub fn list_resources(&self) -> Vec<Resource> {
         let guard = self.inner.read();
-        guard.resources.values().cloned().collect()
+        guard.resources.values().map(|r| r.to_resource()).collect()
     }
 
     pub fn get_resource(&self, uri: &str) -> Option<Resource> {
         let guard = self.inner.read();
-        guard.resources.get(uri).cloned()
+        guard.resources.get(uri).map(|r| r.to_resource())
+    }
+
+    pub fn list_templates(&self) -> Vec<ResourceTemplate> {
+        let guard = self.inner.read();
+        guard
+            .templates
+            .iter()
+            .map(|template| template.provider.to_template())
+            .collect()
+    }
+
+    /// Reads a registered resource, or else resolves `uri` against the first matching template.
+    pub async fn read_resource(&self, uri: &str) -> Result<ResourceContent> {
+        let resource = self.inner.read().resources.get(uri).cloned();
+        let (mime_type, payload) = match resource {
+            Some(resource) => (resource.to_resource().mime_type, resource.read().await?),
+            None => {
+                let (template, variables) = self
+                    .match_template(uri)
+                    .ok_or_else(|| not_found!("Resource not found: {}", uri))?;
+                (
+                    template.to_template().mime_type,
+                    template.read(&variables).await?,
+                )
+            }
+        };
+
+        Ok(ResourceContent {
+            uri: uri.to_string(),
+            mime_type: mime_type.unwrap_or_else(|| "text/plain".to_string()),
+            content: match payload {
+                ResourcePayload::Text(text) => ResourceContentType::Text { text },
+                ResourcePayload::Blob(bytes) => ResourceContentType::Blob {
+                    blob: STANDARD.encode(bytes),
+                },
+            },
+        })
+    }
+
+    fn match_template(
+        &self,
+        uri: &str,
+    ) -> Option<(Arc<dyn TemplateProvider>, HashMap<String, String>)> {
+        let guard = self.inner.read();
+        guard.templates.iter().find_map(|template| {
+            let variables = template.pattern.matches(uri)?;
+            Some((template.provider.clone(), variables))
+        })
     }
 
-    pub fn read_content(&self, uri: &str) -> Option<String> {
+    pub fn add_subscriber(&self, uri: &str) {
+        let mut guard = self.inner.write();
+        *guard.subscriptions.entry(uri.to_string()).or_default() += 1;
+    }
+
+    pub fn remove_subscriber(&self, uri: &str) {
+        let mut guard = self.inner.write();
+        if let Some(count) = guard.subscriptions.get_mut(uri) {
+            *count -= 1;
+            if *count == 0 {
+                guard.subscriptions.remove(uri);
+            }
+        }
+    }
+
+    /// URIs with at least one subscriber.
+    pub fn subscribed(&self) -> Vec<String> {
         let guard = self.inner.read();
-        guard.contents.get(uri).cloned()
+        guard.subscriptions.keys().cloned().collect()
     }
 }
 
@@ -48,30 +172,17 @@ impl ResourceDelegate for ResourceRegistry {
     }
 
     async fn get(&self, uri: &str) -> Result<Option<Resource>> {
-        Ok(self.get_resource(uri).clone())
+        Ok(self.get_resource(uri))
     }
 
     async fn read(&self, uri: &str) -> Result<ResourceContent> {
-        let resource = self
-            .get_resource(uri)
-            .ok_or_else(|| anyhow!("Resource not found: {}", uri))?;
-        let content = self
-            .read_content(uri)
-            .ok_or_else(|| anyhow!("Content not found for resource: {}", uri))?;
-
-        Ok(ResourceContent {
-            uri: uri.to_string(),
-            mime_type: resource
-                .mime_type
-                .clone()
-                .unwrap_or_else(|| "text/plain".to_string()),
-            content: ResourceContentType::Text {
-                text: content.clone(),
-            },
-        })
+        self.read_resource(uri).await
     }
 
-    async fn subscribe(&self, _uri: &str) -> Result<()> {
+    /// Only validates the URI by reading it once; sessions track their own subscriptions.
+    async fn subscribe(&self, uri: &str) -> Result<()> {
+        self.read_resource(uri).await?;
+
         Ok(())
     }
 
@@ -79,3 +190,66 @@ impl ResourceDelegate for ResourceRegistry {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn blobs_are_base64_encoded() {
+        let registry = ResourceRegistry::default();
+        crate::holidays::register_resources(&registry).unwrap();
+
+        let content = registry
+            .read_resource("holidays://US/2027.ics")
+            .await
+            .unwrap();
+        assert_eq!(content.mime_type, "text/calendar");
+        let ResourceContentType::Blob { blob } = content.content else {
+            panic!("expected a blob");
+        };
+        let calendar = String::from_utf8(STANDARD.decode(blob).unwrap()).unwrap();
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.contains("DTSTART;VALUE=DATE:20270705\r\nDTEND;VALUE=DATE:20270706\r\nSUMMARY:Independence Day (observed)\r\n"));
+    }
+
+    #[tokio::test]
+    async fn holidays_are_served_as_json_for_any_year_and_subdivision() {
+        let registry = ResourceRegistry::default();
+        crate::holidays::register_resources(&registry).unwrap();
+
+        let read = |uri: &'static str| {
+            let registry = &registry;
+            async move {
+                let content = registry.read_resource(uri).await?;
+                assert_eq!(content.mime_type, "application/json");
+                let ResourceContentType::Text { text } = content.content else {
+                    panic!("expected text");
+                };
+                anyhow::Ok(serde_json::from_str::<Vec<serde_json::Value>>(&text)?)
+            }
+        };
+
+        let national = read("holidays://US/2031").await.unwrap();
+        assert!(national
+            .iter()
+            .all(|holiday| holiday["date"].as_str().unwrap().starts_with("2031-")));
+        let texas = read("holidays://US/TX/2031").await.unwrap();
+        assert!(texas.len() > national.len());
+        assert_eq!(read("holidays://us/us-tx/2031").await.unwrap(), texas);
+        assert!(read("holidays://US/XX/2031").await.is_err());
+        assert!(read("holidays://US/20x1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unknown_uris_are_not_found() {
+        let registry = ResourceRegistry::default();
+        crate::holidays::register_resources(&registry).unwrap();
+
+        assert!(registry
+            .read_resource("holidays://US/2027.pdf")
+            .await
+            .is_err());
+        assert!(registry.read_resource("nothing://here").await.is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::Display;
+
+use context_server::ContextServerRpcRequest;
+use serde_json::{json, Value};
+
+use crate::error::RequestError;
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+/// MCP's code for an unknown resource, also used here for unknown tools and prompts.
+pub const NOT_FOUND: i64 = -32002;
+
+/// Methods `ContextServer` or the server itself answers; anything else is reported as `Method not found`.
+const METHODS: &[&str] = &[
+    "initialize",
+    "notifications/initialized",
+    "ping",
+    "tools/list",
+    "tools/call",
+    "prompts/list",
+    "prompts/get",
+    "resources/list",
+    "resources/read",
+    "resources/templates/list",
+    "resources/subscribe",
+    "resources/unsubscribe",
+    "completion/complete",
+];
+
+/// A request, or the error response to send back in its place.
+pub type ParsedRequest = Result<ContextServerRpcRequest, Value>;
+
+/// A single JSON-RPC message or a batch of them.
+pub enum Message {
+    Single(ParsedRequest),
+    Batch(Vec<ParsedRequest>),
+}
+
+/// Parses one JSON-RPC message, or returns the error response to send back instead.
+///
+/// Malformed JSON is a parse error and an empty batch an invalid request, both with a null id.
+/// Each element of a non-empty batch is validated on its own.
+pub fn parse_message(message: &str) -> Result<Message, Value> {
+    let value: Value = serde_json::from_str(message)
+        .map_err(|e| error_response(Value::Null, PARSE_ERROR, format!("Parse error: {}", e)))?;
+
+    match value {
+        Value::Array(items) if items.is_empty() => Err(error_response(
+            Value::Null,
+            INVALID_REQUEST,
+            "Invalid Request: empty batch",
+        )),
+        Value::Array(items) => Ok(Message::Batch(
+            items.into_iter().map(parse_request).collect(),
+        )),
+        value => Ok(Message::Single(parse_request(value))),
+    }
+}
+
+/// Well-formed JSON that is not a request is an invalid request, echoing the id whenever one
+/// can be recovered.
+fn parse_request(value: Value) -> ParsedRequest {
+    let id = match value.get("id") {
+        Some(id @ (Value::String(_) | Value::Number(_))) => id.clone(),
+        _ => Value::Null,
+    };
+
+    if value.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        return Err(error_response(
+            id,
+            INVALID_REQUEST,
+            "Invalid Request: jsonrpc must be \"2.0\"",
+        ));
+    }
+
+    serde_json::from_value(value)
+        .map_err(|e| error_response(id, INVALID_REQUEST, format!("Invalid Request: {}", e)))
+}
+
+pub fn is_known_method(method: &str) -> bool {
+    METHODS.contains(&method)
+}
+
+pub fn result_response(id: Value, result: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "result": result,
+    })
+}
+
+pub fn error_response(id: Value, code: i64, message: impl Display) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": code,
+            "message": message.to_string(),
+        },
+    })
+}
+
+/// The error response for a failed request, with a code chosen by the kind of failure.
+pub fn failure_response(id: Value, error: &anyhow::Error) -> Value {
+    let code = match error.downcast_ref::<RequestError>() {
+        Some(RequestError::NotFound(_)) => NOT_FOUND,
+        Some(RequestError::InvalidArguments(_)) => INVALID_PARAMS,
+        None => INTERNAL_ERROR,
+    };
+
+    error_response(id, code, format!("{:#}", error))
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use context_server::ContextServerRpcRequest;
+use futures::future::join_all;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+
+use crate::{
+    rpc::{self, Message, ParsedRequest},
+    ContextServerState,
+};
+
+/// Most cancellations kept for requests that have not arrived yet.
+const MAX_EARLY_CANCELLATIONS: usize = 1024;
+
+/// Per-client protocol state. Request ids are only unique within one client, so each stdio
+/// stream, socket connection or HTTP session tracks its own in-flight requests.
+#[derive(Default)]
+pub struct Session {
+    in_flight: Mutex<InFlight>,
+    subscriptions: Mutex<Subscriptions>,
+}
+
+#[derive(Default)]
+struct InFlight {
+    requests: HashMap<String, oneshot::Sender<()>>,
+    /// Ids cancelled before their request was dispatched, oldest first. Messages are handled on
+    /// their own tasks, so a cancellation can overtake its request; ids are never reused within
+    /// a session, so remembering them is safe.
+    cancelled: VecDeque<String>,
+}
+
+#[derive(Default)]
+struct Subscriptions {
+    uris: HashSet<String>,
+    /// Set once the client is gone, so a subscribe that completes afterwards is not counted.
+    closed: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelledParams {
+    request_id: Value,
+}
+
+#[derive(Deserialize)]
+struct SubscribeParams {
+    uri: String,
+}
+
+impl Session {
+    /// Handles one raw JSON-RPC message, answering malformed input with an error response.
+    pub async fn handle_message(
+        &self,
+        server: &ContextServerState,
+        message: &str,
+    ) -> Option<Value> {
+        match rpc::parse_message(message) {
+            Ok(message) => self.handle(server, message).await,
+            Err(error) => Some(error),
+        }
+    }
+
+    /// Handles a parsed message. The elements of a batch run concurrently and their responses
+    /// come back as one array, which is omitted when every element was a notification.
+    pub async fn handle(&self, server: &ContextServerState, message: Message) -> Option<Value> {
+        match message {
+            Message::Single(request) => self.handle_request(server, request).await,
+            Message::Batch(requests) => {
+                let responses = join_all(
+                    requests
+                        .into_iter()
+                        .map(|request| self.handle_request(server, request)),
+                )
+                .await
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+        }
+    }
+
+    async fn handle_request(
+        &self,
+        server: &ContextServerState,
+        request: ParsedRequest,
+    ) -> Option<Value> {
+        match request {
+            Ok(request) => self.dispatch(server, request).await,
+            Err(error) => Some(error),
+        }
+    }
+
+    /// Dispatches a request so that a `notifications/cancelled` can abort it, even one handled
+    /// before the request itself. A cancelled request gets no response.
+    pub async fn dispatch(
+        &self,
+        server: &ContextServerState,
+        request: ContextServerRpcRequest,
+    ) -> Option<Value> {
+        if request.method == "notifications/cancelled" {
+            self.cancel(request.params);
+            return None;
+        }
+
+        let subscription = match request.method.as_str() {
+            "resources/subscribe" | "resources/unsubscribe" => request
+                .params
+                .clone()
+                .and_then(|params| serde_json::from_value::<SubscribeParams>(params).ok())
+                .map(|params| (request.method == "resources/subscribe", params.uri)),
+            _ => None,
+        };
+
+        let Some(key) = request.id.as_ref().map(Value::to_string) else {
+            return server.dispatch(request).await;
+        };
+
+        let (cancel, cancelled) = oneshot::channel();
+        {
+            let mut in_flight = self.in_flight.lock();
+            if let Some(index) = in_flight.cancelled.iter().position(|id| *id == key) {
+                in_flight.cancelled.remove(index);
+                return None;
+            }
+            in_flight.requests.insert(key.clone(), cancel);
+        }
+
+        let response = tokio::select! {
+            response = server.dispatch(request) => response,
+            Ok(()) = cancelled => None,
+        };
+
+        self.in_flight.lock().requests.remove(&key);
+
+        if let (Some((subscribe, uri)), Some(response)) = (subscription, &response) {
+            if response.get("error").is_none() {
+                let mut subscriptions = self.subscriptions.lock();
+                if subscribe {
+                    if !subscriptions.closed && subscriptions.uris.insert(uri.clone()) {
+                        server.resource_registry.add_subscriber(&uri);
+                    }
+                } else if subscriptions.uris.remove(&uri) {
+                    server.resource_registry.remove_subscriber(&uri);
+                }
+            }
+        }
+
+        response
+    }
+
+    /// The `notifications/resources/updated` message for `uri`, if this session subscribed.
+    pub fn notification_for(&self, uri: &str) -> Option<Value> {
+        self.subscriptions.lock().uris.contains(uri).then(|| {
+            json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/resources/updated",
+                "params": { "uri": uri },
+            })
+        })
+    }
+
+    /// Drops this session's subscriptions once its client is gone.
+    pub fn close(&self, server: &ContextServerState) {
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.closed = true;
+        for uri in subscriptions.uris.drain() {
+            server.resource_registry.remove_subscriber(&uri);
+        }
+    }
+
+    fn cancel(&self, params: Option<Value>) {
+        let Some(params) =
+            params.and_then(|params| serde_json::from_value::<CancelledParams>(params).ok())
+        else {
+            return;
+        };
+
+        let key = params.request_id.to_string();
+        let mut in_flight = self.in_flight.lock();
+        match in_flight.requests.remove(&key) {
+            Some(cancel) => {
+                let _ = cancel.send(());
+            }
+            None => {
+                if in_flight.cancelled.len() == MAX_EARLY_CANCELLATIONS {
+                    in_flight.cancelled.pop_front();
+                }
+                in_flight.cancelled.push_back(key);
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use chrono::{Datelike, Locale, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono_tz::Tz;
+use clap::ValueEnum;
+use parking_lot::RwLock;
+use serde::Deserialize;
+
+use crate::{business_days::Weekend, format::TimeFormat};
+
+/// Server-wide defaults applied when a tool or prompt call leaves the argument out.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    pub timezone: Option<Tz>,
+    pub locale: Option<Locale>,
+    pub format: Option<TimeFormat>,
+    /// Names accepted in place of an IANA time zone, e.g. "london-office".
+    pub team_zones: BTreeMap<String, Tz>,
+    /// Names accepted in place of a strftime pattern.
+    pub formats: BTreeMap<String, TimeFormat>,
+    pub working_hours: Option<WorkingHours>,
+    /// Extra non-working dates, such as company-wide days off.
+    pub holidays: Vec<CustomHoliday>,
+    /// How long a tool call may run; `None` means [`DEFAULT_TOOL_TIMEOUT`].
+    pub tool_timeout: Option<Duration>,
+    /// Per-tool overrides of `tool_timeout`.
+    pub tool_timeouts: BTreeMap<String, Duration>,
+    pub resource_updates: ResourceUpdates,
+}
+
+/// How often subscribers to the time resources are told that the content changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceUpdates {
+    #[default]
+    Minute,
+    Hour,
+    /// At local midnight in the resource's time zone.
+    Day,
+}
+
+impl ResourceUpdates {
+    /// Whether a minute boundary at `local` is an update tick.
+    pub fn is_due(self, local: NaiveDateTime) -> bool {
+        match self {
+            ResourceUpdates::Minute => true,
+            ResourceUpdates::Hour => local.minute() == 0,
+            ResourceUpdates::Day => local.hour() == 0 && local.minute() == 0,
+        }
+    }
+}
+
+pub const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(30);
+
+impl Settings {
+    pub fn timeout_for(&self, tool: &str) -> Duration {
+        self.tool_timeouts
+            .get(tool)
+            .copied()
+            .or(self.tool_timeout)
+            .unwrap_or(DEFAULT_TOOL_TIMEOUT)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkingHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub weekend: Weekend,
+}
+
+impl WorkingHours {
+    /// Whether `datetime` falls on a working day within `[start, end)`; an end before the start
+    /// spans midnight.
+    pub fn contains(&self, datetime: NaiveDateTime, holidays: &[CustomHoliday]) -> bool {
+        let date = datetime.date();
+        let time = datetime.time();
+        let within = if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        };
+
+        within
+            && !self.weekend.contains(date.weekday())
+            && !holidays.iter().any(|holiday| holiday.date == date)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomHoliday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+static SETTINGS: RwLock<Settings> = RwLock::new(Settings {
+    timezone: None,
+    locale: None,
+    format: None,
+    team_zones: BTreeMap::new(),
+    formats: BTreeMap::new(),
+    working_hours: None,
+    holidays: Vec::new(),
+    tool_timeout: None,
+    tool_timeouts: BTreeMap::new(),
+    resource_updates: ResourceUpdates::Minute,
+});
+
+pub fn settings() -> Settings {
+    SETTINGS.read().clone()
+}
+
+pub fn set_settings(settings: Settings) {
+    *SETTINGS.write() = settings;
+}
+
+/// Which tools and prompts get registered. An empty `enable` list allows everything.
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    pub enable: Vec<String>,
+    pub disable: Vec<String>,
+}
+
+impl Selection {
+    pub fn allows(&self, name: &str) -> bool {
+        (self.enable.is_empty() || self.enable.iter().any(|enabled| enabled == name))
+            && !self.disable.iter().any(|disabled| disabled == name)
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use tokio::sync::watch;
+
+/// How long in-flight requests may keep running once shutdown starts.
+pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Resolves once SIGINT or SIGTERM has been received. Clones share the same signal.
+#[derive(Clone)]
+pub struct Shutdown(watch::Receiver<bool>);
+
+impl Shutdown {
+    pub fn listen() -> Result<Self> {
+        let (sender, receiver) = watch::channel(false);
+
+        #[cfg(unix)]
+        let mut terminate =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
+        tokio::spawn(async move {
+            #[cfg(unix)]
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = terminate.recv() => {}
+            }
+            #[cfg(not(unix))]
+            let _ = tokio::signal::ctrl_c().await;
+
+            eprintln!("Shutting down");
+            let _ = sender.send(true);
+        });
+
+        Ok(Self(receiver))
+    }
+
+    pub async fn requested(&mut self) {
+        let _ = self.0.wait_for(|requested| *requested).await;
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    fs::{self, DirBuilder},
+    io::ErrorKind,
+    os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt},
+    path::{Path, PathBuf},
+    process,
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use tokio::{
+    io::BufReader,
+    net::{UnixListener, UnixStream},
+    task::JoinSet,
+    time::sleep,
+};
+
+use crate::{serve_lines, shutdown::Shutdown, ContextServerState};
+
+/// Pause after a failed `accept`, which is usually transient, such as running out of file
+/// descriptors until a connection closes.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// One client connection with its own session, so a slow or disconnected client never holds
+/// up the others.
+struct Connection {
+    id: u64,
+    stream: UnixStream,
+}
+
+/// Accepts clients on the Unix socket at `path`, each speaking newline-delimited JSON-RPC.
+///
+/// On shutdown, new connections are refused, open ones finish their in-flight requests, and
+/// the socket file is removed.
+pub async fn serve(
+    server: Arc<ContextServerState>,
+    path: PathBuf,
+    mut shutdown: Shutdown,
+) -> Result<()> {
+    remove_stale_socket(&path).await?;
+    let listener =
+        bind_private(&path).with_context(|| format!("Failed to bind socket {}", path.display()))?;
+    eprintln!("Listening on {}", path.display());
+
+    let mut connections = JoinSet::new();
+    let mut next_id = 0;
+    loop {
+        let stream = tokio::select! {
+            _ = shutdown.requested() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    eprintln!("Failed to accept a connection: {}", e);
+                    sleep(ACCEPT_RETRY_DELAY).await;
+                    continue;
+                }
+            },
+        };
+
+        next_id += 1;
+        let connection = Connection {
+            id: next_id,
+            stream,
+        };
+        connections.spawn(connection.run(server.clone(), shutdown.clone()));
+    }
+
+    drop(listener);
+    let _ = fs::remove_file(&path);
+    while connections.join_next().await.is_some() {}
+
+    Ok(())
+}
+
+/// Binds the socket inside a private directory next to `path` and restricts it to the owner
+/// before moving it into place, so it is never reachable with the umask's permissions.
+fn bind_private(path: &Path) -> Result<UnixListener> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("{} is not a file path", path.display()))?;
+    let staging = path.with_file_name(format!(".{}.{}", name.to_string_lossy(), process::id()));
+    DirBuilder::new().mode(0o700).create(&staging)?;
+
+    let staged = staging.join("socket");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o600))?;
+        fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = fs::remove_dir_all(&staging);
+
+    Ok(bound?)
+}
+
+impl Connection {
+    async fn run(self, server: Arc<ContextServerState>, shutdown: Shutdown) {
+        let (reader, writer) = self.stream.into_split();
+        match serve_lines(server, BufReader::new(reader), writer, shutdown).await {
+            Ok(()) => eprintln!("Session {} closed", self.id),
+            Err(e) => eprintln!("Session {} ended: {:#}", self.id, e),
+        }
+    }
+}
+
+/// Removes a socket file left behind by a previous run, refusing to touch one still in use or
+/// anything that is not a socket.
+async fn remove_stale_socket(path: &Path) -> Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if !metadata.file_type().is_socket() => {
+            bail!("{} exists and is not a socket", path.display())
+        }
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to inspect {}", path.display())),
+    }
+
+    match UnixStream::connect(path).await {
+        Ok(_) => bail!("Socket {} is already in use", path.display()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(_) => fs::remove_file(path)
+            .with_context(|| format!("Failed to remove stale socket {}", path.display())),
+    }
+}
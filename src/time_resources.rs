@@ -0,0 +1,216 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Datelike, DurationRound, Months, NaiveDate, TimeDelta, Utc};
+use chrono_tz::Tz;
+use context_server::Resource;
+use serde_json::json;
+use tokio::{sync::broadcast, time::sleep};
+
+use crate::{
+    error::invalid_arguments,
+    resource_registry::{
+        ResourcePayload, ResourceProvider, ResourceRegistry, ResourceTemplate, TemplateProvider,
+    },
+    settings::settings,
+    timezone::{default_timezone, parse_timezone},
+    TimeInfo,
+};
+
+const NOW_URI: &str = "time://now";
+const TEAM_URI_PREFIX: &str = "time://team/";
+const ZONE_URI_PREFIX: &str = "time://zone/";
+
+/// Registers the `time://zone/{tz}` and `calendar://{year}/{month}` templates.
+pub fn register_templates(registry: &ResourceRegistry) -> Result<()> {
+    registry.register_template(Arc::new(ZoneTemplate))?;
+    registry.register_template(Arc::new(MonthCalendarTemplate))
+}
+
+/// Registers `time://now` and one `time://team/{name}` resource per configured team zone,
+/// dropping those of team zones that no longer exist.
+pub fn register_resources(registry: &ResourceRegistry) {
+    let team_zones = settings().team_zones;
+
+    for resource in registry.list_resources() {
+        if let Some(name) = resource.uri.strip_prefix(TEAM_URI_PREFIX) {
+            if !team_zones.contains_key(name) {
+                registry.unregister(&resource.uri);
+            }
+        }
+    }
+
+    registry.register(Arc::new(CurrentTimeResource {
+        uri: NOW_URI.into(),
+        name: "Current time".into(),
+        description: "The current time in the server's default time zone".into(),
+        timezone: None,
+    }));
+
+    for (name, timezone) in team_zones {
+        registry.register(Arc::new(CurrentTimeResource {
+            uri: format!("{}{}", TEAM_URI_PREFIX, name),
+            name: format!("Current time for {}", name),
+            description: format!("The current time in {} ({})", name, timezone.name()),
+            timezone: Some(timezone),
+        }));
+    }
+}
+
+/// The current time in `timezone`, or the default time zone when `None`.
+struct CurrentTimeResource {
+    uri: String,
+    name: String,
+    description: String,
+    timezone: Option<Tz>,
+}
+
+#[async_trait]
+impl ResourceProvider for CurrentTimeResource {
+    fn to_resource(&self) -> Resource {
+        Resource {
+            uri: self.uri.clone(),
+            name: self.name.clone(),
+            description: Some(self.description.clone()),
+            mime_type: Some("application/json".into()),
+        }
+    }
+
+    async fn read(&self) -> Result<ResourcePayload> {
+        current_time(self.timezone)
+    }
+}
+
+struct ZoneTemplate;
+
+#[async_trait]
+impl TemplateProvider for ZoneTemplate {
+    fn to_template(&self) -> ResourceTemplate {
+        ResourceTemplate {
+            uri_template: format!("{}{{tz}}", ZONE_URI_PREFIX),
+            name: "Current time in a time zone".into(),
+            description: Some(
+                "The current time in an IANA time zone or team zone, e.g. time://zone/Europe/Berlin"
+                    .into(),
+            ),
+            mime_type: Some("application/json".into()),
+        }
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<ResourcePayload> {
+        current_time(Some(parse_timezone(&variables["tz"])?))
+    }
+}
+
+struct MonthCalendarTemplate;
+
+#[async_trait]
+impl TemplateProvider for MonthCalendarTemplate {
+    fn to_template(&self) -> ResourceTemplate {
+        ResourceTemplate {
+            uri_template: "calendar://{year}/{month}".into(),
+            name: "Month calendar".into(),
+            description: Some(
+                "Every day of a month with its weekday, ISO week and whether it is a working day under the configured working week and holidays, e.g. calendar://2027/02".into(),
+            ),
+            mime_type: Some("application/json".into()),
+        }
+    }
+
+    async fn read(&self, variables: &HashMap<String, String>) -> Result<ResourcePayload> {
+        month_calendar(&variables["year"], &variables["month"])
+    }
+}
+
+fn current_time(timezone: Option<Tz>) -> Result<ResourcePayload> {
+    let settings = settings();
+    let info = TimeInfo::current(timezone, settings.format.as_ref(), settings.locale)?;
+
+    Ok(ResourcePayload::Text(serde_json::to_string_pretty(&info)?))
+}
+
+fn month_calendar(year: &str, month: &str) -> Result<ResourcePayload> {
+    let year = year
+        .parse::<i32>()
+        .map_err(|_| invalid_arguments!("Invalid year: {}", year))?;
+    let month = month
+        .parse::<u32>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))
+        .ok_or_else(|| invalid_arguments!("Invalid month: {} (expected 01 to 12)", month))?;
+    let first = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| invalid_arguments!("Year out of range: {}", year))?;
+    let next = first
+        .checked_add_months(Months::new(1))
+        .ok_or_else(|| invalid_arguments!("Year out of range: {}", year))?;
+
+    let settings = settings();
+    let weekend = settings
+        .working_hours
+        .map(|hours| hours.weekend)
+        .unwrap_or_default();
+    let days = first
+        .iter_days()
+        .take_while(|date| *date < next)
+        .map(|date| {
+            let holiday = settings
+                .holidays
+                .iter()
+                .find(|holiday| holiday.date == date);
+            json!({
+                "date": date.to_string(),
+                "weekday": date.format("%A").to_string(),
+                "iso_week": date.iso_week().week(),
+                "working_day": !weekend.contains(date.weekday()) && holiday.is_none(),
+                "holiday": holiday.map(|holiday| &holiday.name),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ResourcePayload::Text(serde_json::to_string_pretty(
+        &json!({
+            "year": year,
+            "month": month,
+            "month_name": first.format("%B").to_string(),
+            "days": days,
+        }),
+    )?))
+}
+
+fn timezone_of(uri: &str) -> Option<Tz> {
+    if uri == NOW_URI {
+        return Some(default_timezone());
+    }
+    if let Some(name) = uri.strip_prefix(ZONE_URI_PREFIX) {
+        return parse_timezone(name).ok();
+    }
+
+    let name = uri.strip_prefix(TEAM_URI_PREFIX)?;
+    settings().team_zones.get(name).copied()
+}
+
+/// Announces subscribed time resources on `updates` at every tick of the configured
+/// [`ResourceUpdates`](crate::settings::ResourceUpdates) interval.
+pub fn spawn_updates(registry: Arc<ResourceRegistry>, updates: broadcast::Sender<String>) {
+    tokio::spawn(async move {
+        loop {
+            let now = Utc::now();
+            let Ok(tick) = (now + TimeDelta::minutes(1)).duration_trunc(TimeDelta::minutes(1))
+            else {
+                return;
+            };
+            sleep((tick - now).to_std().unwrap_or_default()).await;
+
+            let interval = settings().resource_updates;
+            for uri in registry.subscribed() {
+                let Some(timezone) = timezone_of(&uri) else {
+                    continue;
+                };
+                if interval.is_due(tick.with_timezone(&timezone).naive_local()) {
+                    let _ = updates.send(uri);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use chrono::{
+    DateTime, Duration, MappedLocalTime, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc,
+};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+use crate::{error::invalid_arguments, gazetteer::find_zone, settings::settings};
+
+const NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+];
+
+/// How to resolve a local time that maps to zero or two instants in a time zone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Disambiguation {
+    /// Fail with an explanation of the transition.
+    #[default]
+    Reject,
+    /// Pick the earlier instant; inside a gap, shift back by the gap length.
+    Earlier,
+    /// Pick the later instant; inside a gap, shift forward by the gap length.
+    Later,
+}
+
+pub fn host_timezone() -> Option<Tz> {
+    iana_time_zone::get_timezone().ok()?.parse().ok()
+}
+
+/// The configured default time zone, else the host's, falling back to UTC when neither is known.
+pub fn default_timezone() -> Tz {
+    settings()
+        .timezone
+        .or_else(host_timezone)
+        .unwrap_or(Tz::UTC)
+}
+
+/// The current instant in `timezone`. Every tool and prompt reads the clock through here.
+pub fn now(timezone: Tz) -> DateTime<Tz> {
+    Utc::now().with_timezone(&timezone)
+}
+
+/// Parses an IANA time zone name, one of the configured team zone names or a city name from
+/// the gazetteer.
+pub fn parse_timezone(name: &str) -> Result<Tz> {
+    parse_timezone_with(name, &settings().team_zones)
+}
+
+/// Like [`parse_timezone`], with `team_zones` in place of the configured ones.
+pub fn parse_timezone_with(name: &str, team_zones: &BTreeMap<String, Tz>) -> Result<Tz> {
+    let timezone = name
+        .parse::<Tz>()
+        .ok()
+        .or_else(|| team_zones.get(name).copied());
+    if let Some(timezone) = timezone {
+        return Ok(timezone);
+    }
+
+    find_zone(name)?.ok_or_else(|| invalid_arguments!("Unknown time zone or place: {}", name))
+}
+
+/// Parses a naive local date-time; a bare `YYYY-MM-DD` date means midnight.
+pub fn parse_naive_datetime(input: &str) -> Option<NaiveDateTime> {
+    NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(Default::default()))
+        })
+}
+
+/// Parses an RFC 3339 timestamp, or a naive local date-time interpreted in `timezone`.
+pub fn parse_datetime(
+    input: &str,
+    timezone: Tz,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<Tz>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(input) {
+        return Ok(datetime.with_timezone(&timezone));
+    }
+
+    let naive = parse_naive_datetime(input).ok_or_else(|| {
+        invalid_arguments!(
+            "Invalid date-time: {} (expected RFC 3339, YYYY-MM-DDTHH:MM[:SS] or YYYY-MM-DD)",
+            input
+        )
+    })?;
+
+    resolve_local(timezone, naive, disambiguation)
+}
+
+/// Maps a wall-clock time in `timezone` to an instant, surfacing DST ambiguities and gaps.
+pub fn resolve_local(
+    timezone: Tz,
+    naive: NaiveDateTime,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<Tz>> {
+    match timezone.from_local_datetime(&naive) {
+        MappedLocalTime::Single(datetime) => Ok(datetime),
+        MappedLocalTime::Ambiguous(earlier, later) => match disambiguation {
+            Disambiguation::Reject => {
+                bail!(invalid_arguments!(
+                    "Ambiguous local time {} in {}: it occurs at both {} and {}. \
+                     Pass \"disambiguation\": \"earlier\" or \"later\" to choose one.",
+                    naive,
+                    timezone.name(),
+                    earlier.to_rfc3339(),
+                    later.to_rfc3339()
+                ))
+            }
+            Disambiguation::Earlier => Ok(earlier),
+            Disambiguation::Later => Ok(later),
+        },
+        MappedLocalTime::None => {
+            let before = offset_seconds(timezone, naive - Duration::days(1));
+            let after = offset_seconds(timezone, naive + Duration::days(1));
+
+            match disambiguation {
+                Disambiguation::Reject => {
+                    bail!(invalid_arguments!(
+                        "Local time {} does not exist in {}: clocks skip from UTC{} to UTC{} \
+                         at the daylight saving transition. \
+                         Pass \"disambiguation\": \"earlier\" or \"later\" to shift it.",
+                        naive,
+                        timezone.name(),
+                        format_offset(before),
+                        format_offset(after)
+                    ))
+                }
+                Disambiguation::Earlier => {
+                    Ok(timezone.from_utc_datetime(&(naive - Duration::seconds(after))))
+                }
+                Disambiguation::Later => {
+                    Ok(timezone.from_utc_datetime(&(naive - Duration::seconds(before))))
+                }
+            }
+        }
+    }
+}
+
+fn offset_seconds(timezone: Tz, utc: NaiveDateTime) -> i64 {
+    timezone
+        .offset_from_utc_datetime(&utc)
+        .fix()
+        .local_minus_utc() as i64
+}
+
+fn format_offset(seconds: i64) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+    format!("{}{:02}:{:02}", sign, seconds / 3600, seconds % 3600 / 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Europe::Berlin;
+
+    use super::*;
+
+    fn resolve(naive: &str, disambiguation: Disambiguation) -> Result<String> {
+        let naive = NaiveDateTime::parse_from_str(naive, "%Y-%m-%dT%H:%M").unwrap();
+        Ok(resolve_local(Berlin, naive, disambiguation)?.to_rfc3339())
+    }
+
+    #[test]
+    fn skipped_times_shift_across_the_gap() {
+        let skipped = "2026-03-29T02:30";
+        let error = resolve(skipped, Disambiguation::Reject).unwrap_err();
+        assert!(error.to_string().contains("UTC+01:00 to UTC+02:00"));
+        assert_eq!(
+            resolve(skipped, Disambiguation::Earlier).unwrap(),
+            "2026-03-29T01:30:00+01:00"
+        );
+        assert_eq!(
+            resolve(skipped, Disambiguation::Later).unwrap(),
+            "2026-03-29T03:30:00+02:00"
+        );
+    }
+
+    #[test]
+    fn repeated_times_pick_an_offset() {
+        let repeated = "2026-10-25T02:30";
+        let error = resolve(repeated, Disambiguation::Reject).unwrap_err();
+        assert!(error.to_string().contains("2026-10-25T02:30:00+02:00"));
+        assert!(error.to_string().contains("2026-10-25T02:30:00+01:00"));
+        assert_eq!(
+            resolve(repeated, Disambiguation::Earlier).unwrap(),
+            "2026-10-25T02:30:00+02:00"
+        );
+        assert_eq!(
+            resolve(repeated, Disambiguation::Later).unwrap(),
+            "2026-10-25T02:30:00+01:00"
+        );
+    }
+
+    #[test]
+    fn unambiguous_times_ignore_disambiguation() {
+        for disambiguation in [
+            Disambiguation::Reject,
+            Disambiguation::Earlier,
+            Disambiguation::Later,
+        ] {
+            assert_eq!(
+                resolve("2026-10-18T12:00", disambiguation).unwrap(),
+                "2026-10-18T12:00:00+02:00"
+            );
+        }
+    }
+}
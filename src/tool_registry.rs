@@ -5,6 +5,9 @@ use async_trait::async_trait;
 use context_server::{Tool, ToolContent, ToolDelegate, ToolExecutor};
 use parking_lot::RwLock;
 use serde_json::Value;
+use tokio::runtime::Handle;
+
+use crate::{error::not_found, settings::settings};
 
 #[derive(Default)]
 pub struct ToolRegistry(RwLock<HashMap<String, Arc<dyn ToolExecutor>>>);
@@ -14,19 +17,38 @@ impl ToolRegistry {
         self.0.write().insert(tool.to_tool().name.clone(), tool);
     }
 
+    pub fn unregister(&self, name: &str) {
+        self.0.write().remove(name);
+    }
+
     pub fn list(&self) -> Vec<Tool> {
         self.0.read().values().map(|t| t.to_tool()).collect()
     }
 
-    pub async fn execute(&self, tool: &str, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
+    /// Runs a tool, failing if it takes longer than its configured timeout. Tools do their work
+    /// synchronously, so they run on a blocking thread where the timeout can still fire; a tool
+    /// that times out or is cancelled finishes in the background and its result is dropped.
+    pub async fn execute(&self, name: &str, arguments: Option<Value>) -> Result<Vec<ToolContent>> {
         let tool = self
             .0
             .read()
-            .get(tool)
-            .ok_or_else(|| anyhow!("Tool not found: {}", tool))?
+            .get(name)
+            .ok_or_else(|| not_found!("Tool not found: {}", name))?
             .clone();
 
-        tool.execute(arguments).await
+        let timeout = settings().timeout_for(name);
+        let runtime = Handle::current();
+        let task = tokio::task::spawn_blocking(move || runtime.block_on(tool.execute(arguments)));
+        tokio::time::timeout(timeout, task)
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "Tool {} timed out after {} seconds",
+                    name,
+                    timeout.as_secs()
+                )
+            })?
+            .map_err(|error| anyhow!("Tool {} failed: {}", name, error))?
     }
 }
 
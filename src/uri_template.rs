@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+
+/// A URI template made of literals and simple `{name}` expressions (RFC 6570 level 1).
+pub struct UriTemplate {
+    parts: Vec<Part>,
+}
+
+enum Part {
+    Literal(String),
+    Variable(String),
+}
+
+impl UriTemplate {
+    pub fn parse(template: &str) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                parts.push(Part::Literal(rest[..start].to_string()));
+            } else if matches!(parts.last(), Some(Part::Variable(_))) {
+                bail!("Adjacent variables in URI template: {}", template);
+            }
+            let Some(end) = rest[start..].find('}') else {
+                bail!("Unclosed expression in URI template: {}", template);
+            };
+            let name = &rest[start + 1..start + end];
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                bail!("Invalid variable {:?} in URI template: {}", name, template);
+            }
+            parts.push(Part::Variable(name.to_string()));
+            rest = &rest[start + end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Literal(rest.to_string()));
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// The variables of `uri` if it matches. A variable ends at the next literal, except the last
+    /// one, which takes the rest of the URI (slashes included). Values are percent-decoded and
+    /// never empty.
+    pub fn matches(&self, uri: &str) -> Option<HashMap<String, String>> {
+        let mut variables = HashMap::new();
+        let mut rest = uri;
+
+        for (index, part) in self.parts.iter().enumerate() {
+            match part {
+                Part::Literal(literal) => rest = rest.strip_prefix(literal.as_str())?,
+                Part::Variable(name) => {
+                    let end = match self.parts.get(index + 1) {
+                        Some(Part::Literal(next)) => rest.find(next.as_str())?,
+                        _ => rest.len(),
+                    };
+                    if end == 0 {
+                        return None;
+                    }
+                    variables.insert(name.clone(), percent_decode(&rest[..end])?);
+                    rest = &rest[end..];
+                }
+            }
+        }
+
+        rest.is_empty().then_some(variables)
+    }
+}
+
+fn percent_decode(input: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut rest = input.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(template: &str, uri: &str) -> Option<Vec<(String, String)>> {
+        let mut variables = UriTemplate::parse(template)
+            .unwrap()
+            .matches(uri)?
+            .into_iter()
+            .collect::<Vec<_>>();
+        variables.sort();
+        Some(variables)
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Option<Vec<(String, String)>> {
+        Some(
+            pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn rejects_malformed_templates() {
+        for template in [
+            "time://{tz",
+            "a://{x}{y}",
+            "a://{}",
+            "a://{a-b}",
+            "a://{a b}",
+        ] {
+            assert!(UriTemplate::parse(template).is_err(), "{}", template);
+        }
+        assert!(UriTemplate::parse("time://now").is_ok());
+    }
+
+    #[test]
+    fn variables_end_at_the_next_literal() {
+        let template = "calendar://{year}/{month}";
+        assert_eq!(
+            variables(template, "calendar://2027/02"),
+            pairs(&[("month", "02"), ("year", "2027")])
+        );
+        assert_eq!(variables(template, "calendar://2027"), None);
+        assert_eq!(variables(template, "calendar:///02"), None);
+        assert_eq!(variables(template, "calendar://2027/"), None);
+        assert_eq!(variables(template, "holidays://2027/02"), None);
+
+        let template = "holidays://{country}/{year}.ics";
+        assert_eq!(
+            variables(template, "holidays://US/2027.ics"),
+            pairs(&[("country", "US"), ("year", "2027")])
+        );
+        assert_eq!(variables(template, "holidays://US/2027"), None);
+        assert_eq!(variables(template, "holidays://US/2027.ics.ics"), None);
+    }
+
+    #[test]
+    fn the_last_variable_takes_the_rest() {
+        assert_eq!(
+            variables(
+                "time://zone/{tz}",
+                "time://zone/America/Argentina/Buenos_Aires"
+            ),
+            pairs(&[("tz", "America/Argentina/Buenos_Aires")])
+        );
+        assert_eq!(variables("time://zone/{tz}", "time://zone/"), None);
+    }
+
+    #[test]
+    fn values_are_percent_decoded() {
+        assert_eq!(
+            variables("time://zone/{tz}", "time://zone/America%2FNew_York"),
+            pairs(&[("tz", "America/New_York")])
+        );
+        assert_eq!(
+            variables("time://zone/{tz}", "time://zone/S%C3%A3o%20Paulo"),
+            pairs(&[("tz", "São Paulo")])
+        );
+        for uri in ["time://zone/%zz", "time://zone/UTC%2", "time://zone/%ff"] {
+            assert_eq!(variables("time://zone/{tz}", uri), None, "{}", uri);
+        }
+    }
+}